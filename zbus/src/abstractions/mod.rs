@@ -8,6 +8,8 @@ mod async_drop;
 pub(crate) mod async_lock;
 pub use async_drop::*;
 pub(crate) mod file;
+mod timeout;
+pub(crate) use timeout::timeout;
 
 // Not macOS-specific itself but only used on macOS.
 #[cfg(target_os = "macos")]
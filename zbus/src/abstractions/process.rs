@@ -1,4 +1,8 @@
-use std::{ffi::OsStr, io::Error, process::Output};
+use std::{
+    ffi::OsStr,
+    io::Error,
+    process::{ExitStatus, Output},
+};
 
 /// An asynchronous wrapper around running and getting command output
 pub async fn run<I, S>(program: S, args: I) -> Result<Output, Error>
@@ -18,3 +22,48 @@ where
         .output()
         .await;
 }
+
+/// An asynchronous wrapper around running a command and getting its exit status.
+///
+/// Unlike [`run`], the output of the command isn't captured: the child inherits the stdio of the
+/// current process.
+#[allow(unused)]
+pub async fn run_status<I, S>(program: S, args: I) -> Result<ExitStatus, Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    #[cfg(not(feature = "tokio"))]
+    return async_process::Command::new(program)
+        .args(args)
+        .status()
+        .await;
+
+    #[cfg(feature = "tokio")]
+    return tokio::process::Command::new(program)
+        .args(args)
+        .status()
+        .await;
+}
+
+/// Same as [`run`] but fails if the command doesn't exit successfully.
+///
+/// The returned error includes the exit status and the captured stderr of the command.
+pub async fn run_checked<I, S>(program: S, args: I) -> Result<Output, Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let name = program.as_ref().to_string_lossy().into_owned();
+    let output = run(program, args).await?;
+    if !output.status.success() {
+        return Err(Error::other(format!(
+            "{} terminated with {}: {}",
+            name,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim(),
+        )));
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,36 @@
+use std::{future::Future, io, time::Duration};
+
+use crate::{Error, Result};
+
+/// Run `fut` to completion, failing with an I/O error of kind [`io::ErrorKind::TimedOut`] if it
+/// doesn't complete within `duration`.
+#[cfg(not(feature = "tokio"))]
+pub(crate) async fn timeout<F, T>(fut: F, duration: Duration) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    use futures_util::future::{select, Either};
+
+    let fut = std::pin::pin!(fut);
+    let timer = async_io::Timer::after(duration);
+    match select(fut, timer).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => Err(timed_out()),
+    }
+}
+
+/// Run `fut` to completion, failing with an I/O error of kind [`io::ErrorKind::TimedOut`] if it
+/// doesn't complete within `duration`.
+#[cfg(feature = "tokio")]
+pub(crate) async fn timeout<F, T>(fut: F, duration: Duration) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    tokio::time::timeout(duration, fut)
+        .await
+        .map_err(|_| timed_out())?
+}
+
+fn timed_out() -> Error {
+    Error::InputOutput(io::Error::new(io::ErrorKind::TimedOut, "timeout").into())
+}
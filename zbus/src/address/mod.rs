@@ -15,6 +15,7 @@ use crate::{Error, Guid, OwnedGuid, Result};
 #[cfg(all(unix, not(target_os = "macos")))]
 use nix::unistd::Uid;
 use std::{collections::HashMap, env, str::FromStr};
+use tracing::debug;
 
 use std::fmt::{Display, Formatter};
 
@@ -63,44 +64,14 @@ impl Address {
     /// variable. If we don't recognize the value (or it's not set) we fall back to
     /// `$XDG_RUNTIME_DIR/bus`.
     pub fn session() -> Result<Self> {
-        match env::var("DBUS_SESSION_BUS_ADDRESS") {
-            Ok(val) => Self::from_str(&val),
-            _ => {
-                #[cfg(windows)]
-                return Self::from_str("autolaunch:");
-
-                #[cfg(all(unix, not(target_os = "macos")))]
-                {
-                    let runtime_dir = env::var("XDG_RUNTIME_DIR")
-                        .unwrap_or_else(|_| format!("/run/user/{}", Uid::effective()));
-                    let path = format!("unix:path={runtime_dir}/bus");
-
-                    Self::from_str(&path)
-                }
-
-                #[cfg(target_os = "macos")]
-                return Self::from_str("launchd:env=DBUS_LAUNCHD_SESSION_BUS_SOCKET");
-            }
-        }
+        Self::from_str(&session_address())
     }
 
     /// Get the address for the system bus respecting the `DBUS_SYSTEM_BUS_ADDRESS` environment
     /// variable. If we don't recognize the value (or it's not set) we fall back to
     /// `/var/run/dbus/system_bus_socket`.
     pub fn system() -> Result<Self> {
-        match env::var("DBUS_SYSTEM_BUS_ADDRESS") {
-            Ok(val) => Self::from_str(&val),
-            _ => {
-                #[cfg(all(unix, not(target_os = "macos")))]
-                return Self::from_str("unix:path=/var/run/dbus/system_bus_socket");
-
-                #[cfg(windows)]
-                return Self::from_str("autolaunch:");
-
-                #[cfg(target_os = "macos")]
-                return Self::from_str("launchd:env=DBUS_LAUNCHD_SESSION_BUS_SOCKET");
-            }
-        }
+        Self::from_str(&system_address())
     }
 
     /// The GUID for this address, if known.
@@ -174,11 +145,170 @@ impl From<Transport> for Address {
     }
 }
 
+/// The session bus address(es) from the `DBUS_SESSION_BUS_ADDRESS` environment variable, or the
+/// platform's default if it's not set.
+fn session_address() -> String {
+    env::var("DBUS_SESSION_BUS_ADDRESS").unwrap_or_else(|_| {
+        #[cfg(windows)]
+        return "autolaunch:".to_owned();
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        {
+            let runtime_dir = env::var("XDG_RUNTIME_DIR")
+                .unwrap_or_else(|_| format!("/run/user/{}", Uid::effective()));
+
+            format!("unix:path={runtime_dir}/bus")
+        }
+
+        #[cfg(target_os = "macos")]
+        return "launchd:env=DBUS_LAUNCHD_SESSION_BUS_SOCKET".to_owned();
+    })
+}
+
+/// The system bus address(es) from the `DBUS_SYSTEM_BUS_ADDRESS` environment variable, or the
+/// platform's default if it's not set.
+fn system_address() -> String {
+    env::var("DBUS_SYSTEM_BUS_ADDRESS").unwrap_or_else(|_| {
+        #[cfg(all(unix, not(target_os = "macos")))]
+        return "unix:path=/var/run/dbus/system_bus_socket".to_owned();
+
+        #[cfg(windows)]
+        return "autolaunch:".to_owned();
+
+        #[cfg(target_os = "macos")]
+        return "launchd:env=DBUS_LAUNCHD_SESSION_BUS_SOCKET".to_owned();
+    })
+}
+
+/// A list of bus addresses.
+///
+/// In its string form, the addresses are separated by `;`, e.g `unix:path=/a;tcp:host=b,port=1`.
+/// When connecting, each address is tried in order until one succeeds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressList {
+    addresses: Vec<Address>,
+}
+
+impl AddressList {
+    /// Create a new `AddressList` from the given addresses.
+    pub fn new(addresses: Vec<Address>) -> Self {
+        Self { addresses }
+    }
+
+    /// The addresses in this list.
+    pub fn addresses(&self) -> &[Address] {
+        &self.addresses
+    }
+
+    /// Get the addresses for the session socket respecting the `DBUS_SESSION_BUS_ADDRESS`
+    /// environment variable.
+    ///
+    /// See [`Address::session`] for the fallback if the variable isn't set.
+    pub fn session() -> Result<Self> {
+        Self::from_str(&session_address())
+    }
+
+    /// Get the addresses for the system bus respecting the `DBUS_SYSTEM_BUS_ADDRESS` environment
+    /// variable.
+    ///
+    /// See [`Address::system`] for the fallback if the variable isn't set.
+    pub fn system() -> Result<Self> {
+        Self::from_str(&system_address())
+    }
+
+    /// Connect to each address in turn, until one succeeds.
+    ///
+    /// Returns the stream and the GUID of the address it's connected to. If all the addresses
+    /// fail, the error from the last one is returned.
+    pub(crate) async fn connect(self) -> Result<(Stream, Option<OwnedGuid>)> {
+        let mut last_error = None;
+        for address in self.addresses {
+            let guid = address.guid().map(|g| g.to_owned().into());
+            match address.connect().await {
+                Ok(stream) => return Ok((stream, guid)),
+                Err(e) => {
+                    debug!("Failed to connect to address: {e}");
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| Error::Address("empty address list".to_owned())))
+    }
+}
+
+impl Display for AddressList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, address) in self.addresses.iter().enumerate() {
+            if i > 0 {
+                f.write_str(";")?;
+            }
+            address.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for AddressList {
+    type Err = Error;
+
+    /// Parse a `;`-separated list of D-Bus addresses.
+    fn from_str(addresses: &str) -> Result<Self> {
+        let addresses = addresses
+            .split(';')
+            // A trailing `;` is allowed.
+            .filter(|address| !address.is_empty())
+            .map(Address::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        if addresses.is_empty() {
+            return Err(Error::Address("address list is empty".to_owned()));
+        }
+
+        Ok(Self { addresses })
+    }
+}
+
+impl TryFrom<&str> for AddressList {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::from_str(value)
+    }
+}
+
+impl From<Address> for AddressList {
+    fn from(address: Address) -> Self {
+        Self::new(vec![address])
+    }
+}
+
+impl From<Transport> for AddressList {
+    fn from(transport: Transport) -> Self {
+        Address::from(transport).into()
+    }
+}
+
+impl From<Vec<Address>> for AddressList {
+    fn from(addresses: Vec<Address>) -> Self {
+        Self::new(addresses)
+    }
+}
+
+impl IntoIterator for AddressList {
+    type Item = Address;
+    type IntoIter = std::vec::IntoIter<Address>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.addresses.into_iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         transport::{Tcp, TcpTransportFamily, Transport},
-        Address,
+        Address, AddressList,
     };
     #[cfg(target_os = "macos")]
     use crate::address::transport::Launchd;
@@ -412,6 +542,24 @@ mod tests {
         }
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn percent_encoding() {
+        let addr = Address::from(Transport::Unix(Unix::new(UnixSocket::File(
+            "/tmp/my dir/bus-\u{e9}*\\".into(),
+        ))));
+        // Only bytes outside of the optionally-escaped set are escaped, including each byte of
+        // multi-byte UTF-8 characters.
+        assert_eq!(addr.to_string(), "unix:path=/tmp/my%20dir/bus-%c3%a9*\\");
+        assert_eq!(Address::from_str(&addr.to_string()).unwrap(), addr);
+
+        let addr = Address::from(Transport::Unix(Unix::new(UnixSocket::File(
+            "/a,b=c;d%".into(),
+        ))));
+        assert_eq!(addr.to_string(), "unix:path=/a%2cb%3dc%3bd%25");
+        assert_eq!(Address::from_str(&addr.to_string()).unwrap(), addr);
+    }
+
     #[test]
     fn connect_tcp() {
         let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
@@ -420,6 +568,73 @@ mod tests {
         crate::utils::block_on(async { addr.connect().await }).unwrap();
     }
 
+    #[test]
+    fn address_list() {
+        let list = AddressList::from_str("unix:path=/a;tcp:host=localhost,port=1;").unwrap();
+        assert_eq!(
+            list.addresses(),
+            [
+                Transport::Unix(Unix::new(UnixSocket::File("/a".into()))).into(),
+                Transport::Tcp(Tcp::new("localhost", 1)).into(),
+            ]
+        );
+        assert_eq!(list.to_string(), "unix:path=/a;tcp:host=localhost,port=1");
+        assert_eq!(AddressList::from_str(&list.to_string()).unwrap(), list);
+
+        match AddressList::from_str("").unwrap_err() {
+            Error::Address(e) => assert_eq!(e, "address list is empty"),
+            _ => panic!(),
+        }
+        match AddressList::from_str("unix:path=/a;foo").unwrap_err() {
+            Error::Address(e) => assert_eq!(e, "address has no colon"),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn connect_address_list() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // The first address doesn't exist, so the second one should be used.
+        let list = AddressList::from_str(&format!(
+            "unix:path=/zbus/no/such/socket;tcp:host=localhost,port={port},guid={}",
+            crate::Guid::generate()
+        ))
+        .unwrap();
+        let (_, guid) = crate::utils::block_on(list.connect()).unwrap();
+        assert!(guid.is_some());
+
+        // All failing, the last error is returned.
+        let list =
+            AddressList::from_str("unix:path=/zbus/no/such/socket;tcp:host=localhost,port=1")
+                .unwrap();
+        assert!(crate::utils::block_on(list.connect()).is_err());
+    }
+
+    #[test]
+    fn connect_transport() {
+        // Builders accept a `Transport` directly, as they did before they took address lists.
+        let transport = Address::session().unwrap().transport().clone();
+        assert_eq!(
+            AddressList::from(transport.clone()),
+            AddressList::from(Address::from(transport.clone()))
+        );
+
+        let conn = crate::blocking::connection::Builder::address(transport.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(conn.unique_name().is_some());
+
+        let conn = crate::utils::block_on(
+            crate::connection::Builder::address(transport)
+                .unwrap()
+                .build(),
+        )
+        .unwrap();
+        assert!(conn.unique_name().is_some());
+    }
+
     #[test]
     fn connect_nonce_tcp() {
         struct PercentEncoded<'a>(&'a [u8]);
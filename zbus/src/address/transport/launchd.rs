@@ -1,5 +1,5 @@
 use super::{Transport, Unix, UnixSocket};
-use crate::{process::run, Result};
+use crate::{process::run_checked, Result};
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,16 +24,9 @@ impl Launchd {
 
     /// Determine the actual transport details behind a launchd address.
     pub(super) async fn bus_address(&self) -> Result<Transport> {
-        let output = run("launchctl", ["getenv", self.env()])
+        let output = run_checked("launchctl", ["getenv", self.env()])
             .await
-            .expect("failed to wait on launchctl output");
-
-        if !output.status.success() {
-            return Err(crate::Error::Address(format!(
-                "launchctl terminated with code: {}",
-                output.status
-            )));
-        }
+            .map_err(|e| crate::Error::Address(format!("failed to run launchctl: {e}")))?;
 
         let addr = String::from_utf8(output.stdout).map_err(|e| {
             crate::Error::Address(format!("Unable to parse launchctl output as UTF-8: {}", e))
@@ -1,7 +1,5 @@
-#[cfg(target_os = "linux")]
-use std::ffi::OsString;
 use std::{
-    ffi::OsStr,
+    ffi::{OsStr, OsString},
     fmt::{Display, Formatter},
     path::PathBuf,
 };
@@ -37,17 +35,17 @@ impl Unix {
         let dir = opts.get("dir");
         let tmpdir = opts.get("tmpdir");
         let path = match (path, abs, dir, tmpdir) {
-            (Some(p), None, None, None) => UnixSocket::File(PathBuf::from(p)),
+            (Some(p), None, None, None) => UnixSocket::File(PathBuf::from(decode_unix_path(p)?)),
             #[cfg(target_os = "linux")]
-            (None, Some(p), None, None) => UnixSocket::Abstract(OsString::from(p)),
+            (None, Some(p), None, None) => UnixSocket::Abstract(decode_unix_path(p)?),
             #[cfg(not(target_os = "linux"))]
             (None, Some(_), None, None) => {
                 return Err(crate::Error::Address(
                     "abstract sockets currently Linux-only".to_owned(),
                 ));
             }
-            (None, None, Some(p), None) => UnixSocket::Dir(PathBuf::from(p)),
-            (None, None, None, Some(p)) => UnixSocket::TmpDir(PathBuf::from(p)),
+            (None, None, Some(p), None) => UnixSocket::Dir(PathBuf::from(decode_unix_path(p)?)),
+            (None, None, None, Some(p)) => UnixSocket::TmpDir(PathBuf::from(decode_unix_path(p)?)),
             _ => {
                 return Err(crate::Error::Address("unix: address is invalid".to_owned()));
             }
@@ -57,6 +55,19 @@ impl Unix {
     }
 }
 
+// The reverse of how paths are written in `UnixSocket`'s `Display` impl.
+fn decode_unix_path(path: &str) -> crate::Result<OsString> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+
+        super::decode_percents(path).map(OsString::from_vec)
+    }
+
+    #[cfg(windows)]
+    Ok(OsString::from(path))
+}
+
 impl Display for Unix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "unix:{}", self.path)
@@ -15,8 +15,8 @@ use zvariant::{ObjectPath, Str};
 #[cfg(feature = "p2p")]
 use crate::Guid;
 use crate::{
-    address::Address, blocking::Connection, connection::socket::BoxedSplit, names::WellKnownName,
-    object_server::Interface, utils::block_on, AuthMechanism, Error, Result,
+    address::AddressList, blocking::Connection, connection::socket::BoxedSplit,
+    names::WellKnownName, object_server::Interface, utils::block_on, AuthMechanism, Error, Result,
 };
 
 /// A builder for [`zbus::blocking::Connection`].
@@ -39,10 +39,13 @@ impl<'a> Builder<'a> {
 
     /// Create a builder for a connection that will use the given [D-Bus bus address].
     ///
+    /// Multiple addresses can be given, separated by `;`. They're tried in order when connecting,
+    /// until one succeeds.
+    ///
     /// [D-Bus bus address]: https://dbus.freedesktop.org/doc/dbus-specification.html#addresses
     pub fn address<A>(address: A) -> Result<Self>
     where
-        A: TryInto<Address>,
+        A: TryInto<AddressList>,
         A::Error: Into<Error>,
     {
         crate::connection::Builder::address(address).map(Self)
@@ -96,6 +96,35 @@ impl Connection {
         )
     }
 
+    /// Send a method call without expecting a reply.
+    ///
+    /// Create a method-call message with the `NoReplyExpected` flag set and send it over the
+    /// connection, without waiting for any reply.
+    pub fn call_method_noreply<'d, 'p, 'i, 'm, D, P, I, M, B>(
+        &self,
+        destination: Option<D>,
+        path: P,
+        iface: Option<I>,
+        method_name: M,
+        body: &B,
+    ) -> Result<()>
+    where
+        D: TryInto<BusName<'d>>,
+        P: TryInto<ObjectPath<'p>>,
+        I: TryInto<InterfaceName<'i>>,
+        M: TryInto<MemberName<'m>>,
+        D::Error: Into<Error>,
+        P::Error: Into<Error>,
+        I::Error: Into<Error>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        block_on(
+            self.inner
+                .call_method_noreply(destination, path, iface, method_name, body),
+        )
+    }
+
     /// Emit a signal.
     ///
     /// Create a signal message, and send it over the connection.
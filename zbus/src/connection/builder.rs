@@ -8,6 +8,7 @@ use std::net::TcpStream;
 use std::os::unix::net::UnixStream;
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
     vec,
 };
 #[cfg(feature = "tokio")]
@@ -24,7 +25,7 @@ use vsock::VsockStream;
 use zvariant::{ObjectPath, Str};
 
 use crate::{
-    address::{self, Address},
+    address::{self, AddressList},
     names::{InterfaceName, WellKnownName},
     object_server::{ArcInterface, Interface},
     Connection, Error, Executor, Guid, OwnedGuid, Result,
@@ -47,7 +48,7 @@ enum Target {
         feature = "tokio-vsock"
     ))]
     VsockStream(VsockStream),
-    Address(Address),
+    Address(AddressList),
     Socket(Split<Box<dyn ReadHalf>, Box<dyn WriteHalf>>),
     AuthenticatedSocket(Split<Box<dyn ReadHalf>, Box<dyn WriteHalf>>),
 }
@@ -60,6 +61,7 @@ type Interfaces<'a> = HashMap<ObjectPath<'a>, HashMap<InterfaceName<'static>, Ar
 pub struct Builder<'a> {
     target: Option<Target>,
     max_queued: Option<usize>,
+    method_timeout: Option<Duration>,
     // This is only set for p2p server case or pre-authenticated sockets.
     guid: Option<Guid<'a>>,
     #[cfg(feature = "p2p")]
@@ -79,12 +81,12 @@ assert_impl_all!(Builder<'_>: Send, Sync, Unpin);
 impl<'a> Builder<'a> {
     /// Create a builder for the session/user message bus connection.
     pub fn session() -> Result<Self> {
-        Ok(Self::new(Target::Address(Address::session()?)))
+        Ok(Self::new(Target::Address(AddressList::session()?)))
     }
 
     /// Create a builder for the system-wide message bus connection.
     pub fn system() -> Result<Self> {
-        Ok(Self::new(Target::Address(Address::system()?)))
+        Ok(Self::new(Target::Address(AddressList::system()?)))
     }
 
     /// Create a builder for a connection that will use the given [D-Bus bus address].
@@ -117,10 +119,13 @@ impl<'a> Builder<'a> {
     /// **Note:** The IBus address is different for each session. You can find the address for your
     /// current session using `ibus address` command.
     ///
+    /// Multiple addresses can be given, separated by `;`. They're tried in order when connecting,
+    /// until one succeeds.
+    ///
     /// [D-Bus bus address]: https://dbus.freedesktop.org/doc/dbus-specification.html#addresses
     pub fn address<A>(address: A) -> Result<Self>
     where
-        A: TryInto<Address>,
+        A: TryInto<AddressList>,
         A::Error: Into<Error>,
     {
         Ok(Self::new(Target::Address(
@@ -293,6 +298,37 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Set a timeout for method calls.
+    ///
+    /// Method calls made through the resulting connection (including those made through proxies)
+    /// will fail with an [`Error::InputOutput`] of kind [`std::io::ErrorKind::TimedOut`] if no
+    /// reply is received within `timeout`. By default, method calls wait for a reply indefinitely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::{error::Error, time::Duration};
+    /// # use zbus::connection::Builder;
+    /// # use zbus::block_on;
+    /// #
+    /// # block_on(async {
+    /// let conn = Builder::session()?
+    ///     .method_timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .await?;
+    /// assert_eq!(conn.method_timeout(), Some(Duration::from_secs(5)));
+    ///
+    /// #     Ok::<(), zbus::Error>(())
+    /// # }).unwrap();
+    /// #
+    /// # Ok::<_, Box<dyn Error + Send + Sync>>(())
+    /// ```
+    pub fn method_timeout(mut self, timeout: Duration) -> Self {
+        self.method_timeout = Some(timeout);
+
+        self
+    }
+
     /// Enable or disable the internal executor thread.
     ///
     /// The thread is enabled by default.
@@ -457,7 +493,7 @@ impl<'a> Builder<'a> {
         #[cfg(unix)]
         let already_received_fds = auth.already_received_fds.drain(..).collect();
 
-        let mut conn = Connection::new(auth, is_bus_conn, executor).await?;
+        let mut conn = Connection::new(auth, is_bus_conn, executor, self.method_timeout).await?;
         conn.set_max_queued(self.max_queued.unwrap_or(DEFAULT_MAX_QUEUED));
 
         if !self.interfaces.is_empty() {
@@ -502,6 +538,7 @@ impl<'a> Builder<'a> {
             #[cfg(feature = "p2p")]
             p2p: false,
             max_queued: None,
+            method_timeout: None,
             guid: None,
             internal_executor: true,
             interfaces: HashMap::new(),
@@ -532,9 +569,10 @@ impl<'a> Builder<'a> {
             Target::VsockStream(stream) => Async::new(stream)?.into(),
             #[cfg(feature = "tokio-vsock")]
             Target::VsockStream(stream) => stream.into(),
-            Target::Address(address) => {
-                guid = address.guid().map(|g| g.to_owned().into());
-                match address.connect().await? {
+            Target::Address(addresses) => {
+                let (stream, address_guid) = addresses.connect().await?;
+                guid = address_guid;
+                match stream {
                     #[cfg(any(unix, not(feature = "tokio")))]
                     address::transport::Stream::Unix(stream) => stream.into(),
                     address::transport::Stream::Tcp(stream) => stream.into(),
@@ -121,8 +121,13 @@ impl Client {
     /// be batched with rest of the commands.
     #[instrument(skip(self))]
     async fn authenticate(&mut self) -> Result<Option<Command>> {
+        // The mechanisms the server told us it supports, if it rejected any of ours.
+        let mut server_mechanisms: Option<Vec<AuthMechanism>> = None;
         loop {
-            let mechanism = self.common.next_mechanism()?;
+            let mechanism = match (self.common.next_mechanism(), &server_mechanisms) {
+                (Err(_), Some(offered)) => return Err(Error::AuthRejected(offered.clone())),
+                (res, _) => res?,
+            };
             trace!("Trying {mechanism} mechanism");
             let auth_cmd = match mechanism {
                 AuthMechanism::Anonymous => Command::Auth(Some(mechanism), Some("zbus".into())),
@@ -152,7 +157,14 @@ impl Client {
 
                     return Ok(Some(response));
                 }
-                Command::Rejected(_) => debug!("{mechanism} rejected by the server"),
+                Command::Rejected(offered) => {
+                    debug!("{mechanism} rejected by the server");
+                    // No point in trying mechanisms the server told us it doesn't support.
+                    if !offered.is_empty() {
+                        self.common.retain_mechanisms(|m| offered.contains(m));
+                    }
+                    server_mechanisms = Some(offered);
+                }
                 Command::Error(e) => debug!("Received error from server: {e}"),
                 cmd => {
                     return Err(Error::Handshake(format!(
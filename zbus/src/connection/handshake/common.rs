@@ -49,6 +49,14 @@ impl Common {
         &self.mechanisms
     }
 
+    /// Only keep the AUTH mechanisms for which `f` returns `true`.
+    pub fn retain_mechanisms<F>(&mut self, f: F)
+    where
+        F: FnMut(&AuthMechanism) -> bool,
+    {
+        self.mechanisms.retain(f);
+    }
+
     pub fn into_components(self) -> IntoComponentsReturn {
         (
             self.socket,
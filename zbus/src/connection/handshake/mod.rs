@@ -137,14 +137,14 @@ fn sasl_auth_id() -> Result<String> {
 mod tests {
     use futures_util::future::join;
     #[cfg(not(feature = "tokio"))]
-    use futures_util::io::{AsyncWrite, AsyncWriteExt};
+    use futures_util::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
     use ntest::timeout;
     #[cfg(not(feature = "tokio"))]
     use std::os::unix::net::UnixStream;
     use test_log::test;
     #[cfg(feature = "tokio")]
     use tokio::{
-        io::{AsyncWrite, AsyncWriteExt},
+        io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
         net::UnixStream,
     };
 
@@ -152,7 +152,10 @@ mod tests {
 
     use crate::{Guid, Socket};
 
-    fn create_async_socket_pair() -> (impl AsyncWrite + Socket, impl AsyncWrite + Socket) {
+    fn create_async_socket_pair() -> (
+        impl AsyncRead + AsyncWrite + Socket,
+        impl AsyncRead + AsyncWrite + Socket,
+    ) {
         // Tokio needs us to call the sync function from async context. :shrug:
         let (p0, p1) = crate::utils::block_on(async { UnixStream::pair().unwrap() });
 
@@ -314,4 +317,100 @@ mod tests {
             .unwrap();
         crate::utils::block_on(server.perform()).unwrap();
     }
+
+    #[test]
+    #[timeout(15000)]
+    fn skip_mechanisms_not_offered() {
+        let (p0, mut p1) = create_async_socket_pair();
+        let mechanisms = [
+            AuthMechanism::External,
+            AuthMechanism::Cookie,
+            AuthMechanism::Anonymous,
+        ];
+        let client = Client::new(p0.into(), Some(mechanisms.into()), None, false);
+
+        // A minimal server that only supports ANONYMOUS and fails if the client tries anything
+        // else after having been told so.
+        let server = async move {
+            assert!(read_line(&mut p1).await.starts_with("\0AUTH EXTERNAL"));
+            p1.write_all(b"REJECTED ANONYMOUS\r\n").await.unwrap();
+            assert!(read_line(&mut p1).await.starts_with("AUTH ANONYMOUS"));
+            p1.write_all(format!("OK {}\r\n", Guid::generate()).as_bytes())
+                .await
+                .unwrap();
+            assert_eq!(read_line(&mut p1).await, "NEGOTIATE_UNIX_FD\r\n");
+            p1.write_all(b"AGREE_UNIX_FD\r\n").await.unwrap();
+            assert_eq!(read_line(&mut p1).await, "BEGIN\r\n");
+        };
+
+        crate::utils::block_on(join(async move { client.perform().await.unwrap() }, server));
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn report_server_mechanisms() {
+        let (p0, mut p1) = create_async_socket_pair();
+        let client = Client::new(
+            p0.into(),
+            Some([AuthMechanism::Anonymous].into()),
+            None,
+            false,
+        );
+
+        let server = async move {
+            assert!(read_line(&mut p1).await.starts_with("\0AUTH ANONYMOUS"));
+            p1.write_all(b"REJECTED EXTERNAL DBUS_COOKIE_SHA1\r\n")
+                .await
+                .unwrap();
+        };
+
+        let (res, _) = crate::utils::block_on(join(client.perform(), server));
+        let err = res.unwrap_err();
+        assert_eq!(
+            err,
+            Error::AuthRejected(vec![AuthMechanism::External, AuthMechanism::Cookie])
+        );
+        assert_eq!(
+            err.to_string(),
+            "D-Bus handshake failed: all AUTH mechanisms were rejected, server supports: EXTERNAL \
+             DBUS_COOKIE_SHA1"
+        );
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn report_no_server_mechanisms() {
+        let (p0, mut p1) = create_async_socket_pair();
+        let client = Client::new(
+            p0.into(),
+            Some([AuthMechanism::Anonymous].into()),
+            None,
+            false,
+        );
+
+        let server = async move {
+            assert!(read_line(&mut p1).await.starts_with("\0AUTH ANONYMOUS"));
+            p1.write_all(b"REJECTED\r\n").await.unwrap();
+        };
+
+        let (res, _) = crate::utils::block_on(join(client.perform(), server));
+        let err = res.unwrap_err();
+        assert_eq!(err, Error::AuthRejected(vec![]));
+        assert_eq!(
+            err.to_string(),
+            "D-Bus handshake failed: all AUTH mechanisms were rejected and the server didn't list \
+             any it supports"
+        );
+    }
+
+    async fn read_line(socket: &mut (impl AsyncRead + Unpin)) -> String {
+        let mut line = Vec::new();
+        while !line.ends_with(b"\r\n") {
+            let mut byte = [0];
+            socket.read_exact(&mut byte).await.unwrap();
+            line.push(byte[0]);
+        }
+
+        String::from_utf8(line).unwrap()
+    }
 }
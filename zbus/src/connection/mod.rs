@@ -12,6 +12,7 @@ use std::{
     pin::Pin,
     sync::{Arc, OnceLock, Weak},
     task::{Context, Poll},
+    time::Duration,
 };
 use tracing::{debug, info_span, instrument, trace, trace_span, warn, Instrument};
 use zbus_names::{BusName, ErrorName, InterfaceName, MemberName, OwnedUniqueName, WellKnownName};
@@ -56,6 +57,7 @@ pub(crate) struct ConnectionInner {
     bus_conn: bool,
     unique_name: OnceLock<OwnedUniqueName>,
     registered_names: Mutex<HashMap<WellKnownName<'static>, NameStatus>>,
+    method_timeout: Option<Duration>,
 
     activity_event: Arc<Event>,
     socket_write: Mutex<Box<dyn socket::WriteHalf>>,
@@ -307,7 +309,11 @@ impl Connection {
     /// Create a method-call message, send it over the connection, then wait for the reply.
     ///
     /// On successful reply, an `Ok(Message)` is returned. On error, an `Err` is returned. D-Bus
-    /// error replies are returned as [`Error::MethodError`].
+    /// error replies are returned as [`Error::MethodError`]. If a [method timeout] is set and no
+    /// reply is received in time, an [`Error::InputOutput`] of kind
+    /// [`std::io::ErrorKind::TimedOut`] is returned.
+    ///
+    /// [method timeout]: Connection::method_timeout
     pub async fn call_method<'d, 'p, 'i, 'm, D, P, I, M, B>(
         &self,
         destination: Option<D>,
@@ -316,6 +322,48 @@ impl Connection {
         method_name: M,
         body: &B,
     ) -> Result<Message>
+    where
+        D: TryInto<BusName<'d>>,
+        P: TryInto<ObjectPath<'p>>,
+        I: TryInto<InterfaceName<'i>>,
+        M: TryInto<MemberName<'m>>,
+        D::Error: Into<Error>,
+        P::Error: Into<Error>,
+        I::Error: Into<Error>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let reply = self
+            .call_method_raw(
+                destination,
+                path,
+                interface,
+                method_name,
+                BitFlags::empty(),
+                body,
+            )
+            .await?
+            .expect("no reply");
+
+        match self.method_timeout() {
+            Some(duration) => crate::abstractions::timeout(reply, duration).await,
+            None => reply.await,
+        }
+    }
+
+    /// Send a method call without expecting a reply.
+    ///
+    /// Create a method-call message with the `NoReplyExpected` flag set and send it over the
+    /// connection. This returns as soon as the message is sent, without waiting for (or receiving)
+    /// any reply.
+    pub async fn call_method_noreply<'d, 'p, 'i, 'm, D, P, I, M, B>(
+        &self,
+        destination: Option<D>,
+        path: P,
+        interface: Option<I>,
+        method_name: M,
+        body: &B,
+    ) -> Result<()>
     where
         D: TryInto<BusName<'d>>,
         P: TryInto<ObjectPath<'p>>,
@@ -332,12 +380,11 @@ impl Connection {
             path,
             interface,
             method_name,
-            BitFlags::empty(),
+            Flags::NoReplyExpected.into(),
             body,
         )
-        .await?
-        .expect("no reply")
         .await
+        .map(|_| ())
     }
 
     /// Send a method call.
@@ -838,6 +885,13 @@ impl Connection {
         self.inner.msg_receiver.clone().set_capacity(max);
     }
 
+    /// The timeout for method calls, if any.
+    ///
+    /// See [`Builder::method_timeout`] for details.
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.inner.method_timeout
+    }
+
     /// The server's GUID.
     pub fn server_guid(&self) -> &OwnedGuid {
         &self.inner.server_guid
@@ -1127,6 +1181,7 @@ impl Connection {
         auth: Authenticated,
         #[allow(unused)] bus_connection: bool,
         executor: Executor<'static>,
+        method_timeout: Option<Duration>,
     ) -> Result<Self> {
         #[cfg(unix)]
         let cap_unix_fd = auth.cap_unix_fd;
@@ -1168,6 +1223,7 @@ impl Connection {
                 #[cfg(feature = "p2p")]
                 bus_conn: bus_connection,
                 unique_name: OnceLock::new(),
+                method_timeout,
                 subscriptions,
                 object_server: OnceLock::new(),
                 object_server_dispatch_task: OnceLock::new(),
@@ -1440,6 +1496,71 @@ mod tests {
         assert!(!name_has_owner);
     }
 
+    #[test]
+    #[timeout(15000)]
+    fn method_timeout() {
+        crate::utils::block_on(test_method_timeout());
+    }
+
+    async fn test_method_timeout() {
+        let timeout = Duration::from_millis(100);
+        let connection = Builder::session()
+            .unwrap()
+            .method_timeout(timeout)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(connection.method_timeout(), Some(timeout));
+
+        // `peer` doesn't serve anything and hence will never reply.
+        let peer = Connection::session().await.unwrap();
+        let err = connection
+            .call_method(
+                peer.unique_name(),
+                "/org/zbus/NoReply",
+                Some("org.zbus.NoReply"),
+                "NoReply",
+                &(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InputOutput(e) if e.kind() == ErrorKind::TimedOut));
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn method_noreply() {
+        crate::utils::block_on(test_method_noreply());
+    }
+
+    async fn test_method_noreply() {
+        use futures_util::stream::TryStreamExt;
+
+        let connection = Connection::session().await.unwrap();
+        // `peer` doesn't serve anything and hence would never reply.
+        let peer = Connection::session().await.unwrap();
+        let mut stream = MessageStream::from(&peer);
+
+        connection
+            .call_method_noreply(
+                peer.unique_name(),
+                "/org/zbus/NoReply",
+                Some("org.zbus.NoReply"),
+                "NoReply",
+                &(),
+            )
+            .await
+            .unwrap();
+
+        while let Some(msg) = stream.try_next().await.unwrap() {
+            let header = msg.header();
+            if header.member().map(|m| m.as_str()) == Some("NoReply") {
+                assert!(header.primary().flags().contains(Flags::NoReplyExpected));
+                break;
+            }
+        }
+    }
+
     #[tokio::test(start_paused = true)]
     #[timeout(15000)]
     async fn test_graceful_shutdown() {
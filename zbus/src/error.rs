@@ -1,11 +1,12 @@
 use static_assertions::assert_impl_all;
 use std::{convert::Infallible, error, fmt, io, sync::Arc};
 use zbus_names::{Error as NamesError, InterfaceName, OwnedErrorName};
-use zvariant::{Error as VariantError, ObjectPath};
+use zvariant::{parsed, Error as VariantError, ObjectPath};
 
 use crate::{
     fdo,
     message::{Message, Type},
+    AuthMechanism,
 };
 
 /// The error type for `zbus`.
@@ -33,6 +34,14 @@ pub enum Error {
     IncorrectEndian,
     /// Initial handshake error.
     Handshake(String),
+    /// The server rejected all the authentication mechanisms that were tried.
+    ///
+    /// The argument is the list of mechanisms the server listed as supported in its last
+    /// `REJECTED` reply, which can be empty if it didn't list any. Enabling one of these through
+    /// [`connection::Builder::auth_mechanisms`] might help.
+    ///
+    /// [`connection::Builder::auth_mechanisms`]: crate::connection::Builder::auth_mechanisms
+    AuthRejected(Vec<AuthMechanism>),
     /// Unexpected or incorrect reply.
     InvalidReply,
     /// A D-Bus method error reply.
@@ -71,6 +80,7 @@ impl PartialEq for Error {
             (Self::Address(_), Self::Address(_)) => true,
             (Self::InterfaceNotFound, Self::InterfaceNotFound) => true,
             (Self::Handshake(_), Self::Handshake(_)) => true,
+            (Self::AuthRejected(s), Self::AuthRejected(o)) => s == o,
             (Self::InvalidReply, Self::InvalidReply) => true,
             (Self::ExcessData, Self::ExcessData) => true,
             (Self::IncorrectEndian, Self::IncorrectEndian) => true,
@@ -101,6 +111,7 @@ impl error::Error for Error {
             Error::InputOutput(e) => Some(e),
             Error::ExcessData => None,
             Error::Handshake(_) => None,
+            Error::AuthRejected(_) => None,
             Error::IncorrectEndian => None,
             Error::Variant(e) => Some(e),
             Error::Names(e) => Some(e),
@@ -129,6 +140,22 @@ impl fmt::Display for Error {
             Error::ExcessData => write!(f, "excess data"),
             Error::InputOutput(e) => write!(f, "I/O error: {e}"),
             Error::Handshake(e) => write!(f, "D-Bus handshake failed: {e}"),
+            Error::AuthRejected(mechanisms) if mechanisms.is_empty() => write!(
+                f,
+                "D-Bus handshake failed: all AUTH mechanisms were rejected and the server didn't \
+                 list any it supports"
+            ),
+            Error::AuthRejected(mechanisms) => {
+                write!(
+                    f,
+                    "D-Bus handshake failed: all AUTH mechanisms were rejected, server supports:"
+                )?;
+                for mechanism in mechanisms {
+                    write!(f, " {mechanism}")?;
+                }
+
+                Ok(())
+            }
             Error::IncorrectEndian => write!(f, "incorrect endian"),
             Error::InvalidField => write!(f, "invalid message field"),
             Error::Variant(e) => write!(f, "{e}"),
@@ -164,6 +191,7 @@ impl Clone for Error {
             Error::ExcessData => Error::ExcessData,
             Error::InputOutput(e) => Error::InputOutput(e.clone()),
             Error::Handshake(e) => Error::Handshake(e.clone()),
+            Error::AuthRejected(m) => Error::AuthRejected(m.clone()),
             Error::IncorrectEndian => Error::IncorrectEndian,
             Error::InvalidField => Error::InvalidField,
             Error::Variant(e) => Error::Variant(e.clone()),
@@ -241,10 +269,19 @@ impl From<Message> for Error {
 
         if let Some(name) = header.error_name() {
             let name = name.to_owned().into();
-            match message.body().deserialize_unchecked::<&str>() {
-                Ok(detail) => Error::MethodError(name, Some(String::from(detail)), message),
-                Err(_) => Error::MethodError(name, None, message),
-            }
+            // The error description, if any, is the first argument and must be a string.
+            let body = message.body();
+            let detail = match body.parsed_signature() {
+                Ok(signature)
+                    if signature == parsed::Signature::Str
+                        || signature.fields().next() == Some(&parsed::Signature::Str) =>
+                {
+                    body.deserialize_unchecked::<&str>().ok().map(String::from)
+                }
+                _ => None,
+            };
+
+            Error::MethodError(name, detail, message)
         } else {
             Error::InvalidReply
         }
@@ -27,13 +27,7 @@ impl Body {
     where
         B: zvariant::DynamicDeserialize<'s>,
     {
-        let body_sig = self
-            .msg
-            .header()
-            .signature()
-            .map(|s| parsed::Signature::from_str(s))
-            .transpose()?
-            .unwrap_or(parsed::Signature::Unit);
+        let body_sig = self.parsed_signature()?;
 
         self.data
             .deserialize_for_dynamic_parsed_signature(body_sig)
@@ -59,6 +53,23 @@ impl Body {
         self.msg.header().signature().cloned()
     }
 
+    /// The signature of the body, parsed.
+    ///
+    /// Returns [`parsed::Signature::Unit`] if the body has no signature. Unlike
+    /// [`Body::signature`], multiple arguments are represented as a [`parsed::Signature::Structure`].
+    ///
+    /// **Note:** The signature is parsed on each call, since a parsed signature can't be shared
+    /// across threads and hence can't be cached in the message.
+    pub fn parsed_signature(&self) -> Result<parsed::Signature> {
+        self.msg
+            .header()
+            .signature()
+            .map(|s| parsed::Signature::from_str(s))
+            .transpose()
+            .map(|s| s.unwrap_or(parsed::Signature::Unit))
+            .map_err(Error::from)
+    }
+
     /// The length of the body in bytes.
     pub fn len(&self) -> usize {
         self.data.len()
@@ -372,6 +372,7 @@ mod tests {
 
     use super::Message;
     use crate::Error;
+    use zvariant::parsed;
 
     #[test]
     fn test() {
@@ -416,5 +417,30 @@ mod tests {
             .build(&("kaboom!", 32))
             .unwrap();
         assert_eq!(e.to_string(), "Error org.freedesktop.zbus.Error: kaboom!");
+        assert_eq!(
+            e.body().parsed_signature().unwrap(),
+            parsed::Signature::structure([parsed::Signature::Str, parsed::Signature::I32])
+        );
+        match Error::from(e) {
+            Error::MethodError(_, detail, _) => assert_eq!(detail.as_deref(), Some("kaboom!")),
+            e => panic!("unexpected error: {e}"),
+        }
+
+        // No description if the first argument isn't a string.
+        let e = Message::method_error(&m, "org.freedesktop.zbus.Error")
+            .unwrap()
+            .build(&(32u32))
+            .unwrap();
+        assert_eq!(e.body().parsed_signature().unwrap(), parsed::Signature::U32);
+        match Error::from(e) {
+            Error::MethodError(_, detail, _) => assert_eq!(detail, None),
+            e => panic!("unexpected error: {e}"),
+        }
+
+        let m = Message::method("/", "do").unwrap().build(&()).unwrap();
+        assert_eq!(
+            m.body().parsed_signature().unwrap(),
+            parsed::Signature::Unit
+        );
     }
 }
@@ -876,10 +876,8 @@ impl<'a> Proxy<'a> {
         R: for<'d> zvariant::DynamicDeserialize<'d>,
     {
         let flags = flags.iter().map(Flags::from).collect::<BitFlags<_>>();
-        match self
-            .inner
-            .inner_without_borrows
-            .conn
+        let conn = &self.inner.inner_without_borrows.conn;
+        match conn
             .call_method_raw(
                 Some(self.destination()),
                 self.path(),
@@ -890,7 +888,14 @@ impl<'a> Proxy<'a> {
             )
             .await?
         {
-            Some(reply) => reply.await?.body().deserialize().map(Some),
+            Some(reply) => {
+                let reply = match conn.method_timeout() {
+                    Some(duration) => crate::abstractions::timeout(reply, duration).await?,
+                    None => reply.await?,
+                };
+
+                reply.body().deserialize().map(Some)
+            }
             None => Ok(None),
         }
     }
@@ -1,11 +1,11 @@
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "serde_bytes")]
 use serde_bytes::ByteBuf;
-use std::{collections::HashMap, vec};
+use std::{collections::HashMap, str::FromStr, vec};
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use zvariant::{serialized::Context, to_bytes, Type, Value, LE};
+use zvariant::{parsed, serialized::Context, to_bytes, Type, Value, LE};
 
 macro_rules! benchmark {
     ($c:ident, $data:ident, $data_type:ty, $func_prefix:literal) => {
@@ -147,8 +147,61 @@ fn big_array(c: &mut Criterion) {
     );
 }
 
+fn signature_parse(c: &mut Criterion) {
+    let deep_array = format!("{}y", "a".repeat(32));
+    let mut group = c.benchmark_group("signature_parse");
+    for (name, signature) in [("asv", "a{sv}"), ("deep_array", &deep_array)] {
+        // `from_bytes` uses the iterative parser while `parse_first` uses the nom-based one.
+        group.bench_function(format!("{name}_iterative"), |b| {
+            b.iter(|| {
+                let sig = parsed::Signature::from_bytes(black_box(signature.as_bytes())).unwrap();
+                black_box(sig);
+            })
+        });
+        group.bench_function(format!("{name}_nom"), |b| {
+            b.iter(|| {
+                let sig = parsed::Signature::parse_first(black_box(signature.as_bytes())).unwrap();
+                black_box(sig);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn signature_string_len(c: &mut Criterion) {
+    let signature = format!("({})", "a{sa{sv}}".repeat(20));
+    let signature = parsed::Signature::from_str(&signature).unwrap();
+    let mut group = c.benchmark_group("signature_string_len");
+    group.bench_function("string_len", |b| {
+        b.iter(|| {
+            let len = black_box(&signature).string_len();
+            black_box(len);
+        })
+    });
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            let s = black_box(&signature).to_string();
+            black_box(s);
+        })
+    });
+    group.finish();
+}
+
 #[cfg(feature = "serde_bytes")]
-criterion_group!(benches, big_array, byte_array, fixed_size_array);
+criterion_group!(
+    benches,
+    big_array,
+    byte_array,
+    fixed_size_array,
+    signature_parse,
+    signature_string_len
+);
 #[cfg(not(feature = "serde_bytes"))]
-criterion_group!(benches, big_array, fixed_size_array);
+criterion_group!(
+    benches,
+    big_array,
+    fixed_size_array,
+    signature_parse,
+    signature_string_len
+);
 criterion_main!(benches);
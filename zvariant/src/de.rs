@@ -217,6 +217,8 @@ where
         parsed::Signature::Structure { .. } => de.deserialize_seq(visitor),
         #[cfg(feature = "gvariant")]
         parsed::Signature::Maybe(_) => de.deserialize_option(visitor),
+        #[cfg(feature = "gvariant")]
+        parsed::Signature::DictEntry { .. } => de.deserialize_seq(visitor),
     }
 }
 
@@ -61,7 +61,31 @@ pub enum Error {
     /// The maximum allowed depth for containers in encoding was exceeded.
     MaxDepthExceeded(MaxDepthExceeded),
     /// Invalid signature.
+    ///
+    /// This is no longer returned: invalid signatures are reported through
+    /// [`Error::SignatureParse`], or a more specific variant, such as [`Error::SignatureTooDeep`].
+    #[deprecated(
+        since = "4.2.0",
+        note = "Invalid signatures are reported through `SignatureParse`, `SignatureTooDeep` etc"
+    )]
     InvalidSignature,
+    /// Failed to parse a signature.
+    SignatureParse {
+        /// The byte offset in the signature string at which parsing failed.
+        position: usize,
+        /// What the parser expected at `position`.
+        expected: &'static str,
+    },
+    /// The signature nests containers deeper than the D-Bus specification allows.
+    SignatureTooDeep(MaxDepthExceeded),
+    /// The signature contains GVariant-specific types but the `gvariant` feature is disabled.
+    GVariantFeatureDisabled,
+    /// The signature contains an empty structure (`()`), which D-Bus doesn't allow.
+    EmptyStruct,
+    /// The signature contains a dictionary whose key isn't a basic type.
+    InvalidDictKey,
+    /// The signature is longer than the allowed maximum length (the argument).
+    SignatureTooLong(usize),
 }
 
 assert_impl_all!(Error: Send, Sync, Unpin);
@@ -77,6 +101,18 @@ impl PartialEq for Error {
             (Error::UnknownFd, Error::UnknownFd) => true,
             (Error::MaxDepthExceeded(max1), Error::MaxDepthExceeded(max2)) => max1 == max2,
             (Error::MissingFramingOffset, Error::MissingFramingOffset) => true,
+            (
+                Error::SignatureParse { position, expected },
+                Error::SignatureParse {
+                    position: other_position,
+                    expected: other_expected,
+                },
+            ) => position == other_position && expected == other_expected,
+            (Error::SignatureTooDeep(max1), Error::SignatureTooDeep(max2)) => max1 == max2,
+            (Error::GVariantFeatureDisabled, Error::GVariantFeatureDisabled) => true,
+            (Error::EmptyStruct, Error::EmptyStruct) => true,
+            (Error::InvalidDictKey, Error::InvalidDictKey) => true,
+            (Error::SignatureTooLong(max1), Error::SignatureTooLong(max2)) => max1 == max2,
             (_, _) => false,
         }
     }
@@ -118,7 +154,24 @@ impl fmt::Display for Error {
                 "Out of bounds range specified",
             ),
             Error::MaxDepthExceeded(max) => write!(f, "{max}"),
+            #[allow(deprecated)]
             Error::InvalidSignature => write!(f, "Invalid signature"),
+            Error::SignatureParse { position, expected } => write!(
+                f,
+                "Invalid signature: expected {expected} at position {position}"
+            ),
+            Error::SignatureTooDeep(max) => write!(f, "Invalid signature: {max}"),
+            Error::GVariantFeatureDisabled => write!(
+                f,
+                "Signature contains GVariant-specific types but the `gvariant` feature is disabled"
+            ),
+            Error::EmptyStruct => write!(f, "Invalid signature: empty structures are not allowed"),
+            Error::InvalidDictKey => {
+                write!(f, "Invalid signature: dictionary keys must be basic types")
+            }
+            Error::SignatureTooLong(max) => {
+                write!(f, "Signature is longer than the maximum of {max} bytes")
+            }
         }
     }
 }
@@ -141,7 +194,17 @@ impl Clone for Error {
             }
             Error::OutOfBounds => Error::OutOfBounds,
             Error::MaxDepthExceeded(max) => Error::MaxDepthExceeded(*max),
+            #[allow(deprecated)]
             Error::InvalidSignature => Error::InvalidSignature,
+            Error::SignatureParse { position, expected } => Error::SignatureParse {
+                position: *position,
+                expected,
+            },
+            Error::SignatureTooDeep(max) => Error::SignatureTooDeep(*max),
+            Error::GVariantFeatureDisabled => Error::GVariantFeatureDisabled,
+            Error::EmptyStruct => Error::EmptyStruct,
+            Error::InvalidDictKey => Error::InvalidDictKey,
+            Error::SignatureTooLong(max) => Error::SignatureTooLong(*max),
         }
     }
 }
@@ -679,6 +679,20 @@ mod tests {
             .0;
     }
 
+    #[test]
+    fn tuple_with_unit() {
+        assert_eq!(<(u32, (), u32)>::signature(), "(uu)");
+        let ctxt = Context::new_dbus(LE, 0);
+        let encoded = to_bytes(ctxt, &(7u32, (), 9u32)).unwrap();
+        assert_eq!(encoded.bytes(), [7, 0, 0, 0, 9, 0, 0, 0]);
+        let decoded: (u32, (), u32) = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, (7, (), 9));
+
+        let encoded = to_bytes(ctxt, &[(), ()]).unwrap();
+        assert_eq!(encoded.len(), 0);
+        let _: [(); 2] = encoded.deserialize().unwrap().0;
+    }
+
     #[test]
     fn array_value() {
         // Let's use D-Bus/GVariant terms
@@ -1,4 +1,8 @@
-use std::{ops::Deref, rc::Rc};
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
 
 use super::Signature;
 
@@ -8,7 +12,7 @@ pub enum ChildSignature {
     /// A static child signature.
     Static { child: &'static Signature },
     /// A dynamic child signature.
-    Dynamic { child: Rc<Signature> },
+    Dynamic { child: DynamicChild },
 }
 
 impl ChildSignature {
@@ -19,6 +23,17 @@ impl ChildSignature {
             ChildSignature::Dynamic { child } => child,
         }
     }
+
+    /// The size of the string form of the child signature.
+    ///
+    /// Same as [`Signature::string_len`], except that for dynamic child signatures, it's computed
+    /// only once, on creation.
+    pub fn string_len(&self) -> usize {
+        match self {
+            ChildSignature::Static { child } => child.string_len(),
+            ChildSignature::Dynamic { child } => child.string_len(),
+        }
+    }
 }
 
 impl Deref for ChildSignature {
@@ -31,15 +46,15 @@ impl Deref for ChildSignature {
 
 impl From<Rc<Signature>> for ChildSignature {
     fn from(child: Rc<Signature>) -> Self {
-        ChildSignature::Dynamic { child }
+        ChildSignature::Dynamic {
+            child: DynamicChild::from(child),
+        }
     }
 }
 
 impl From<Signature> for ChildSignature {
     fn from(child: Signature) -> Self {
-        ChildSignature::Dynamic {
-            child: Rc::new(child),
-        }
+        Rc::new(child).into()
     }
 }
 
@@ -48,3 +63,54 @@ impl From<&'static Signature> for ChildSignature {
         ChildSignature::Static { child }
     }
 }
+
+/// The child signature of [`ChildSignature::Dynamic`].
+///
+/// This is a reference-counted [`Signature`], along with the size of its string form, computed
+/// once on creation. The size being private ensures that it always matches the signature.
+#[derive(Clone)]
+pub struct DynamicChild {
+    child: Rc<Signature>,
+    string_len: usize,
+}
+
+impl DynamicChild {
+    /// The size of the string form of the signature.
+    pub fn string_len(&self) -> usize {
+        self.string_len
+    }
+
+    /// The reference-counted signature.
+    pub fn as_rc(&self) -> &Rc<Signature> {
+        &self.child
+    }
+}
+
+impl From<Rc<Signature>> for DynamicChild {
+    fn from(child: Rc<Signature>) -> Self {
+        DynamicChild {
+            string_len: child.string_len(),
+            child,
+        }
+    }
+}
+
+impl From<Signature> for DynamicChild {
+    fn from(child: Signature) -> Self {
+        Rc::new(child).into()
+    }
+}
+
+impl Deref for DynamicChild {
+    type Target = Signature;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl Debug for DynamicChild {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.child, f)
+    }
+}
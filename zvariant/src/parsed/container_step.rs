@@ -0,0 +1,17 @@
+/// A step into a container signature.
+///
+/// A list of steps forms a path to a nested signature. See [`super::Signature::child_at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContainerStep {
+    /// The element signature of a [`super::Signature::Array`].
+    ArrayElement,
+    /// The key signature of a [`super::Signature::Dict`].
+    DictKey,
+    /// The value signature of a [`super::Signature::Dict`].
+    DictValue,
+    /// The signature of the field at the given index of a [`super::Signature::Structure`].
+    Field(usize),
+    /// The inner signature of a [`super::Signature::Maybe`].
+    #[cfg(feature = "gvariant")]
+    MaybeInner,
+}
@@ -1,4 +1,8 @@
-use std::rc::Rc;
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
 
 use super::Signature;
 
@@ -9,7 +13,7 @@ pub enum FieldsSignatures {
         fields: &'static [&'static Signature],
     },
     Dynamic {
-        fields: Rc<[Signature]>,
+        fields: DynamicFields,
     },
 }
 
@@ -39,27 +43,35 @@ impl FieldsSignatures {
             FieldsSignatures::Dynamic { fields } => Fields::Dynamic(fields.iter()),
         }
     }
+
+    /// The total size of the string form of the fields' signatures.
+    ///
+    /// For dynamic fields, this is computed only once, on creation.
+    pub(crate) fn string_len(&self) -> usize {
+        match self {
+            FieldsSignatures::Static { fields } => fields.iter().map(|f| f.string_len()).sum(),
+            FieldsSignatures::Dynamic { fields } => fields.string_len(),
+        }
+    }
 }
 
 impl From<Rc<[Signature]>> for FieldsSignatures {
     fn from(fields: Rc<[Signature]>) -> Self {
-        FieldsSignatures::Dynamic { fields }
+        FieldsSignatures::Dynamic {
+            fields: DynamicFields::from(fields),
+        }
     }
 }
 
 impl From<Vec<Signature>> for FieldsSignatures {
     fn from(fields: Vec<Signature>) -> Self {
-        FieldsSignatures::Dynamic {
-            fields: fields.into(),
-        }
+        Rc::<[Signature]>::from(fields).into()
     }
 }
 
 impl<const N: usize> From<[Signature; N]> for FieldsSignatures {
     fn from(fields: [Signature; N]) -> Self {
-        FieldsSignatures::Dynamic {
-            fields: fields.into(),
-        }
+        Rc::<[Signature]>::from(fields).into()
     }
 }
 
@@ -68,3 +80,55 @@ impl From<&'static [&'static Signature]> for FieldsSignatures {
         FieldsSignatures::Static { fields }
     }
 }
+
+/// The fields of [`FieldsSignatures::Dynamic`].
+///
+/// This is a reference-counted list of [`Signature`]s, along with the total size of their string
+/// form, computed once on creation. The size being private ensures that it always matches the
+/// fields.
+#[derive(Clone)]
+pub struct DynamicFields {
+    fields: Rc<[Signature]>,
+    string_len: usize,
+}
+
+impl DynamicFields {
+    /// The total size of the string form of the fields' signatures.
+    pub fn string_len(&self) -> usize {
+        self.string_len
+    }
+
+    /// The reference-counted fields.
+    pub fn as_rc(&self) -> &Rc<[Signature]> {
+        &self.fields
+    }
+}
+
+impl From<Rc<[Signature]>> for DynamicFields {
+    fn from(fields: Rc<[Signature]>) -> Self {
+        DynamicFields {
+            string_len: fields.iter().map(|f| f.string_len()).sum(),
+            fields,
+        }
+    }
+}
+
+impl From<Vec<Signature>> for DynamicFields {
+    fn from(fields: Vec<Signature>) -> Self {
+        Rc::<[Signature]>::from(fields).into()
+    }
+}
+
+impl Deref for DynamicFields {
+    type Target = [Signature];
+
+    fn deref(&self) -> &Self::Target {
+        &self.fields
+    }
+}
+
+impl Debug for DynamicFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.fields, f)
+    }
+}
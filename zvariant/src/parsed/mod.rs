@@ -1,9 +1,15 @@
 mod child_signature;
-pub use child_signature::ChildSignature;
+pub use child_signature::{ChildSignature, DynamicChild};
+mod container_step;
+pub use container_step::ContainerStep;
 mod fields_signatures;
-pub use fields_signatures::FieldsSignatures;
+pub use fields_signatures::{DynamicFields, FieldsSignatures};
 pub mod signature;
 pub use signature::Signature;
+mod signature_error;
+pub use signature_error::SignatureError;
+mod signature_key;
+pub use signature_key::SignatureKey;
 
 #[cfg(test)]
 mod tests;
@@ -1,14 +1,26 @@
 use serde::{Deserialize, Serialize};
+use static_assertions::assert_impl_all;
 
-use super::{child_signature::ChildSignature, fields_signatures::FieldsSignatures};
+use super::{
+    child_signature::ChildSignature, container_step::ContainerStep,
+    fields_signatures::FieldsSignatures, SignatureError,
+};
 
 use core::fmt;
+use nom::{
+    branch::alt,
+    combinator::{all_consuming, eof, map},
+    multi::many1,
+    sequence::{delimited, pair},
+};
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
-use crate::{serialized::Format, Basic, Type};
+use crate::{
+    container_depths::ContainerDepths, serialized::Format, utils::padding_for_n_bytes, Basic, Type,
+};
 
 /// A D-Bus signature in parsed form.
 ///
@@ -89,8 +101,21 @@ pub enum Signature {
     /// The signature for a maybe type (gvariant-specific).
     #[cfg(feature = "gvariant")]
     Maybe(ChildSignature),
+    /// The signature for a dictionary entry outside of a dictionary (gvariant-specific).
+    ///
+    /// Unlike D-Bus, GVariant allows dictionary entries (e.g `{sv}`) as standalone types. They're
+    /// encoded just like a structure with two fields.
+    #[cfg(feature = "gvariant")]
+    DictEntry {
+        /// The signature for the key.
+        key: ChildSignature,
+        /// The signature for the value.
+        value: ChildSignature,
+    },
 }
 
+assert_impl_all!(Signature: Unpin, std::panic::UnwindSafe, std::panic::RefUnwindSafe);
+
 impl Signature {
     /// The size of the string form of `self`.
     pub fn string_len(&self) -> usize {
@@ -113,15 +138,81 @@ impl Signature {
             Signature::Fd => 1,
             Signature::Array(child) => 1 + child.string_len(),
             Signature::Dict { key, value } => 3 + key.string_len() + value.string_len(),
-            Signature::Structure(fields) => {
+            Signature::Structure(fields) => 2 + fields.string_len(),
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(child) => 1 + child.string_len(),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => 2 + key.string_len() + value.string_len(),
+        }
+    }
+
+    /// The size of the string form of `self`, in `const` contexts.
+    ///
+    /// This is the same as [`Signature::string_len`] but can be used in `const` contexts, e.g to
+    /// size arrays at compile time for signatures with only static children.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is (or contains) a container signature with dynamic children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::{ChildSignature, FieldsSignatures, Signature};
+    ///
+    /// const SIGNATURE: Signature = Signature::Structure(FieldsSignatures::Static {
+    ///     fields: &[
+    ///         &Signature::U8,
+    ///         &Signature::Array(ChildSignature::Static {
+    ///             child: &Signature::Str,
+    ///         }),
+    ///     ],
+    /// });
+    /// const LEN: usize = SIGNATURE.string_len_const();
+    ///
+    /// let buf = [0u8; LEN];
+    /// assert_eq!(buf.len(), SIGNATURE.to_string().len());
+    /// ```
+    pub const fn string_len_const(&self) -> usize {
+        match self {
+            Signature::Unit => 0,
+            Signature::U8
+            | Signature::Bool
+            | Signature::I16
+            | Signature::U16
+            | Signature::I32
+            | Signature::U32
+            | Signature::I64
+            | Signature::U64
+            | Signature::F64
+            | Signature::Str
+            | Signature::Signature
+            | Signature::ObjectPath
+            | Signature::Variant => 1,
+            #[cfg(unix)]
+            Signature::Fd => 1,
+            Signature::Array(ChildSignature::Static { child }) => 1 + child.string_len_const(),
+            Signature::Dict {
+                key: ChildSignature::Static { child: key },
+                value: ChildSignature::Static { child: value },
+            } => 3 + key.string_len_const() + value.string_len_const(),
+            Signature::Structure(FieldsSignatures::Static { fields }) => {
                 let mut len = 2;
-                for field in fields.iter() {
-                    len += field.string_len();
+                let mut i = 0;
+                while i < fields.len() {
+                    len += fields[i].string_len_const();
+                    i += 1;
                 }
                 len
             }
             #[cfg(feature = "gvariant")]
-            Signature::Maybe(child) => 1 + child.string_len(),
+            Signature::Maybe(ChildSignature::Static { child }) => 1 + child.string_len_const(),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry {
+                key: ChildSignature::Static { child: key },
+                value: ChildSignature::Static { child: value },
+            } => 2 + key.string_len_const() + value.string_len_const(),
+            _ => panic!("`string_len_const` called on a signature with dynamic children"),
         }
     }
 
@@ -146,12 +237,578 @@ impl Signature {
         s
     }
 
+    /// Append the string form of `self` to `out`, as bytes.
+    ///
+    /// This produces the same output as the `Display::fmt`, but without going through the
+    /// formatting machinery or requiring a `String`.
+    pub fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.reserve(self.string_len());
+        self.write_as_bytes(out, true);
+    }
+
+    /// Convert `self` to its string form, as bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let sig = Signature::from_str("(xa{bs}as)").unwrap();
+    /// assert_eq!(sig.to_bytes(), b"(xa{bs}as)");
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.string_len());
+        self.write_as_bytes(&mut bytes, true);
+
+        bytes
+    }
+
+    /// An iterator over the signatures of the top-level fields, if `self` is a
+    /// [`Signature::Structure`].
+    ///
+    /// For all other variants, the returned iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let sig = Signature::from_str("(xa{bs}as)").unwrap();
+    /// let fields: Vec<_> = sig.fields().map(|f| f.to_string()).collect();
+    /// assert_eq!(fields, ["x", "a{bs}", "as"]);
+    ///
+    /// assert_eq!(Signature::from_str("as").unwrap().fields().count(), 0);
+    /// ```
+    pub fn fields(&self) -> impl Iterator<Item = &Signature> {
+        let fields = match self {
+            Signature::Structure(fields) => Some(fields.iter()),
+            _ => None,
+        };
+
+        fields.into_iter().flatten()
+    }
+
+    /// The number of fields, if `self` is a [`Signature::Structure`], or 0 otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::from_str("(xa{bs}as)").unwrap().n_fields(), 3);
+    /// assert_eq!(Signature::from_str("a(xs)").unwrap().n_fields(), 0);
+    /// ```
+    pub fn n_fields(&self) -> usize {
+        match self {
+            Signature::Structure(FieldsSignatures::Static { fields }) => fields.len(),
+            Signature::Structure(FieldsSignatures::Dynamic { fields }) => fields.len(),
+            _ => 0,
+        }
+    }
+
+    /// The D-Bus type code of `self`, if it's a basic type.
+    ///
+    /// Returns `None` for containers and `Signature::Unit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::U8.type_char(), Some('y'));
+    /// assert_eq!(Signature::Variant.type_char(), Some('v'));
+    /// assert_eq!(Signature::static_array(&Signature::U8).type_char(), None);
+    /// ```
+    pub fn type_char(&self) -> Option<char> {
+        let c = match self {
+            Signature::U8 => 'y',
+            Signature::Bool => 'b',
+            Signature::I16 => 'n',
+            Signature::U16 => 'q',
+            Signature::I32 => 'i',
+            Signature::U32 => 'u',
+            Signature::I64 => 'x',
+            Signature::U64 => 't',
+            Signature::F64 => 'd',
+            Signature::Str => 's',
+            Signature::Signature => 'g',
+            Signature::ObjectPath => 'o',
+            Signature::Variant => 'v',
+            #[cfg(unix)]
+            Signature::Fd => 'h',
+            _ => return None,
+        };
+
+        Some(c)
+    }
+
+    /// The basic type signature for the given D-Bus type code.
+    ///
+    /// This is the inverse of [`Signature::type_char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::from_type_char('s'), Some(Signature::Str));
+    /// assert_eq!(Signature::from_type_char('a'), None);
+    /// ```
+    pub fn from_type_char(c: char) -> Option<Signature> {
+        let signature = match c {
+            'y' => Signature::U8,
+            'b' => Signature::Bool,
+            'n' => Signature::I16,
+            'q' => Signature::U16,
+            'i' => Signature::I32,
+            'u' => Signature::U32,
+            'x' => Signature::I64,
+            't' => Signature::U64,
+            'd' => Signature::F64,
+            's' => Signature::Str,
+            'g' => Signature::Signature,
+            'o' => Signature::ObjectPath,
+            'v' => Signature::Variant,
+            #[cfg(unix)]
+            'h' => Signature::Fd,
+            _ => return None,
+        };
+
+        Some(signature)
+    }
+
+    /// The signature for the given single-character D-Bus type code, without any parsing.
+    ///
+    /// This is a cheaper alternative to parsing a string for signatures known to consist of a
+    /// single basic type code (including `v`), e.g when building signatures one character at a
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// If `c` isn't one of the single-character type codes (e.g a container code like `a` or `(`).
+    /// Use [`Signature::from_type_char`] if `c` isn't known to be valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::from_char_unchecked(b's'), Signature::Str);
+    /// assert_eq!(Signature::from_char_unchecked(b'v'), Signature::Variant);
+    /// ```
+    pub const fn from_char_unchecked(c: u8) -> Signature {
+        match c {
+            b'y' => Signature::U8,
+            b'b' => Signature::Bool,
+            b'n' => Signature::I16,
+            b'q' => Signature::U16,
+            b'i' => Signature::I32,
+            b'u' => Signature::U32,
+            b'x' => Signature::I64,
+            b't' => Signature::U64,
+            b'd' => Signature::F64,
+            b's' => Signature::Str,
+            b'g' => Signature::Signature,
+            b'o' => Signature::ObjectPath,
+            b'v' => Signature::Variant,
+            #[cfg(unix)]
+            b'h' => Signature::Fd,
+            _ => panic!("`from_char_unchecked` called with a non-basic type code"),
+        }
+    }
+
+    /// Whether `self` is a basic type, i-e a type that can be used as a dictionary key.
+    ///
+    /// Basic types are the fixed-sized numeric types, booleans, strings, object paths, signatures
+    /// and file descriptors. Note that [`Signature::Variant`] is **not** a basic type, even though
+    /// its signature is a single character: it's a container of a value of any type. Neither is
+    /// [`Signature::Unit`], or any of the container types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert!(Signature::Str.is_basic());
+    /// assert!(Signature::U32.is_basic());
+    /// assert!(!Signature::Variant.is_basic());
+    /// assert!(!Signature::Unit.is_basic());
+    /// assert!(!Signature::from_str("(ii)").unwrap().is_basic());
+    /// ```
+    pub fn is_basic(&self) -> bool {
+        match self {
+            Signature::U8
+            | Signature::Bool
+            | Signature::I16
+            | Signature::U16
+            | Signature::I32
+            | Signature::U32
+            | Signature::I64
+            | Signature::U64
+            | Signature::F64
+            | Signature::Str
+            | Signature::Signature
+            | Signature::ObjectPath => true,
+            #[cfg(unix)]
+            Signature::Fd => true,
+            _ => false,
+        }
+    }
+
+    /// Whether `self` is a container signature (array, dictionary, structure or maybe).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert!(Signature::from_str("a{sv}").unwrap().is_container());
+    /// assert!(Signature::from_str("(xs)").unwrap().is_container());
+    /// assert!(!Signature::from_str("s").unwrap().is_container());
+    /// ```
+    pub fn is_container(&self) -> bool {
+        match self {
+            Signature::Array(_) | Signature::Dict { .. } | Signature::Structure(_) => true,
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(_) | Signature::DictEntry { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// The signature of the element, if `self` is a [`Signature::Array`] or a `Signature::Maybe`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let sig = Signature::from_str("a(ys)").unwrap();
+    /// assert_eq!(sig.element().unwrap(), "(ys)");
+    ///
+    /// assert!(Signature::from_str("a{sv}").unwrap().element().is_none());
+    /// ```
+    pub fn element(&self) -> Option<&Signature> {
+        match self {
+            Signature::Array(child) => Some(child.signature()),
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(child) => Some(child.signature()),
+            _ => None,
+        }
+    }
+
+    /// The signatures of the key and value, if `self` is a [`Signature::Dict`] or a
+    /// `Signature::DictEntry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let sig = Signature::from_str("a{sv}").unwrap();
+    /// let (key, value) = sig.entry().unwrap();
+    /// assert_eq!(key, &Signature::Str);
+    /// assert_eq!(value, &Signature::Variant);
+    ///
+    /// assert!(Signature::from_str("as").unwrap().entry().is_none());
+    /// ```
+    pub fn entry(&self) -> Option<(&Signature, &Signature)> {
+        match self {
+            Signature::Dict { key, value } => Some((key.signature(), value.signature())),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => Some((key.signature(), value.signature())),
+            _ => None,
+        }
+    }
+
+    /// The nested signature at the given `path`.
+    ///
+    /// Each step of `path` descends one level into a container. Returns `None` if a step doesn't
+    /// match the kind of container it's applied to (or the field index is out of bounds). An empty
+    /// `path` refers to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::{ContainerStep, Signature};
+    ///
+    /// let sig = Signature::from_str("a{s(ua{sv})}").unwrap();
+    /// let child = sig.child_at(&[
+    ///     ContainerStep::DictValue,
+    ///     ContainerStep::Field(1),
+    ///     ContainerStep::DictKey,
+    /// ]);
+    /// assert_eq!(child, Some(&Signature::Str));
+    ///
+    /// assert!(sig.child_at(&[ContainerStep::ArrayElement]).is_none());
+    /// ```
+    pub fn child_at(&self, path: &[ContainerStep]) -> Option<&Signature> {
+        path.iter()
+            .try_fold(self, |signature, step| match (step, signature) {
+                (ContainerStep::ArrayElement, Signature::Array(child)) => Some(child.signature()),
+                (ContainerStep::DictKey, Signature::Dict { key, .. }) => Some(key.signature()),
+                (ContainerStep::DictValue, Signature::Dict { value, .. }) => {
+                    Some(value.signature())
+                }
+                (ContainerStep::Field(i), Signature::Structure(fields)) => fields.iter().nth(*i),
+                #[cfg(feature = "gvariant")]
+                (ContainerStep::MaybeInner, Signature::Maybe(child)) => Some(child.signature()),
+                #[cfg(feature = "gvariant")]
+                (ContainerStep::DictKey, Signature::DictEntry { key, .. }) => Some(key.signature()),
+                #[cfg(feature = "gvariant")]
+                (ContainerStep::DictValue, Signature::DictEntry { value, .. }) => {
+                    Some(value.signature())
+                }
+                _ => None,
+            })
+    }
+
+    /// The maximum nesting depth of containers in `self`.
+    ///
+    /// Basic types have a depth of 0, while each level of array, dictionary, structure or maybe
+    /// adds 1 to the depth of its children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::from_str("s").unwrap().depth(), 0);
+    /// assert_eq!(Signature::from_str("a{sv}").unwrap().depth(), 1);
+    /// assert_eq!(Signature::from_str("(xa{bs}as)").unwrap().depth(), 2);
+    /// assert_eq!(Signature::from_str("aaay").unwrap().depth(), 3);
+    /// ```
+    pub fn depth(&self) -> usize {
+        match self {
+            Signature::Array(child) => 1 + child.depth(),
+            Signature::Dict { key, value } => 1 + key.depth().max(value.depth()),
+            Signature::Structure(fields) => {
+                1 + fields.iter().map(Signature::depth).max().unwrap_or(0)
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(child) => 1 + child.depth(),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => 1 + key.depth().max(value.depth()),
+            _ => 0,
+        }
+    }
+
+    /// A suggested Rust type for values of this signature, e.g for generating code from
+    /// introspection data.
+    ///
+    /// Basic types map to their Rust equivalents, with the owned zvariant types used for strings,
+    /// signatures, object paths, file descriptors and variants. Arrays map to `Vec`, dictionaries to
+    /// `HashMap` and structures to tuples. GVariant maybe types map to `Option`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::U8.rust_type_hint(), "u8");
+    /// assert_eq!(Signature::Str.rust_type_hint(), "String");
+    /// assert_eq!(
+    ///     Signature::from_str("a{sv}").unwrap().rust_type_hint(),
+    ///     "HashMap<String, OwnedValue>",
+    /// );
+    /// assert_eq!(
+    ///     Signature::from_str("(oaay)").unwrap().rust_type_hint(),
+    ///     "(OwnedObjectPath, Vec<Vec<u8>>)",
+    /// );
+    /// ```
+    pub fn rust_type_hint(&self) -> String {
+        let mut s = String::new();
+        self.write_rust_type_hint(&mut s);
+
+        s
+    }
+
+    fn write_rust_type_hint(&self, out: &mut String) {
+        match self {
+            Signature::Unit => out.push_str("()"),
+            Signature::U8 => out.push_str("u8"),
+            Signature::Bool => out.push_str("bool"),
+            Signature::I16 => out.push_str("i16"),
+            Signature::U16 => out.push_str("u16"),
+            Signature::I32 => out.push_str("i32"),
+            Signature::U32 => out.push_str("u32"),
+            Signature::I64 => out.push_str("i64"),
+            Signature::U64 => out.push_str("u64"),
+            Signature::F64 => out.push_str("f64"),
+            Signature::Str => out.push_str("String"),
+            Signature::Signature => out.push_str("OwnedSignature"),
+            Signature::ObjectPath => out.push_str("OwnedObjectPath"),
+            Signature::Variant => out.push_str("OwnedValue"),
+            #[cfg(unix)]
+            Signature::Fd => out.push_str("OwnedFd"),
+            Signature::Array(child) => {
+                out.push_str("Vec<");
+                child.write_rust_type_hint(out);
+                out.push('>');
+            }
+            Signature::Dict { key, value } => {
+                out.push_str("HashMap<");
+                key.write_rust_type_hint(out);
+                out.push_str(", ");
+                value.write_rust_type_hint(out);
+                out.push('>');
+            }
+            Signature::Structure(fields) => {
+                out.push('(');
+                let mut len = 0;
+                for field in fields.iter() {
+                    if len > 0 {
+                        out.push_str(", ");
+                    }
+                    field.write_rust_type_hint(out);
+                    len += 1;
+                }
+                // A single-element tuple needs a trailing comma.
+                if len == 1 {
+                    out.push(',');
+                }
+                out.push(')');
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(child) => {
+                out.push_str("Option<");
+                child.write_rust_type_hint(out);
+                out.push('>');
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => {
+                out.push('(');
+                key.write_rust_type_hint(out);
+                out.push_str(", ");
+                value.write_rust_type_hint(out);
+                out.push(')');
+            }
+        }
+    }
+
+    /// Whether `self` is the empty signature, i-e [`Signature::Unit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert!(Signature::from_str("").unwrap().is_empty());
+    /// assert!(!Signature::from_str("s").unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Signature::Unit)
+    }
+
+    /// Whether `value` conforms to `self`.
+    ///
+    /// This compares `self` against the signature of `value`, so it can be used to validate
+    /// decoded (untrusted) values against an expected signature without re-serializing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{collections::HashMap, str::FromStr};
+    /// use zvariant::{parsed::Signature, Value};
+    ///
+    /// let sig = Signature::from_str("a{sv}").unwrap();
+    /// let dict: HashMap<&str, Value<'_>> = HashMap::from([("id", Value::from(42u32))]);
+    /// assert!(sig.matches(&Value::from(dict)));
+    /// assert!(!sig.matches(&Value::from("a{sv}")));
+    /// ```
+    pub fn matches(&self, value: &crate::Value<'_>) -> bool {
+        *self == value.value_signature()
+    }
+
     /// Parse signature from a byte slice.
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         parse(bytes, false)
     }
 
+    /// Parse signature from a string, returning a [`SignatureError`] on failure.
+    ///
+    /// This is the same as the [`FromStr`] implementation, except that the error keeps the input
+    /// and the position of the error, for rendering a diagnostic to the user.
+    pub fn parse_diagnostic(s: &str) -> Result<Self, SignatureError> {
+        parse_at(s.as_bytes(), false)
+            .map_err(|(e, offset)| SignatureError::new(s.as_bytes(), offset, e))
+    }
+
+    /// Parse a [`crate::Signature`].
+    ///
+    /// Unlike the `From<crate::Signature>` implementation, this doesn't panic if `signature` is
+    /// not valid (which can happen if it was created through one of the `_unchecked`
+    /// constructors).
+    pub fn from_signature(signature: &crate::Signature<'_>) -> crate::Result<Self> {
+        parse(signature.as_bytes(), false)
+    }
+
+    /// The signature of the type `T`.
+    ///
+    /// This is a shorthand for [`Type::parsed_signature`], convenient in generic code. For types
+    /// implementing that method directly (as the derive macros and zvariant's own types do), the
+    /// signature is constructed without going through its string form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zvariant::{parsed::Signature, OwnedValue};
+    ///
+    /// assert_eq!(Signature::of::<u32>(), Signature::U32);
+    /// assert_eq!(Signature::of::<HashMap<String, OwnedValue>>(), "a{sv}");
+    /// assert_eq!(Signature::of::<(u8, &str)>(), "(ys)");
+    /// ```
+    pub fn of<T>() -> Self
+    where
+        T: Type + ?Sized,
+    {
+        T::parsed_signature()
+    }
+
+    /// Parse the first complete type from a byte slice.
+    ///
+    /// Unlike [`Signature::from_bytes`], this doesn't require the whole of `bytes` to be a valid
+    /// signature. Only a single complete type is parsed from the start of `bytes` and returned,
+    /// along with the number of bytes it took up. The rest of `bytes` is left for the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let (sig, len) = Signature::parse_first(b"a{sv}(ii)").unwrap();
+    /// assert_eq!(sig, "a{sv}");
+    /// assert_eq!(len, 5);
+    ///
+    /// // No complete type at the start.
+    /// assert!(Signature::parse_first(b"a{sv").is_err());
+    /// assert!(Signature::parse_first(b"").is_err());
+    /// ```
+    pub fn parse_first(bytes: &[u8]) -> crate::Result<(Self, usize)> {
+        let (remaining, signature) =
+            parse_signature(bytes, false, Nesting::default()).map_err(|e| parse_error(bytes, e))?;
+
+        Ok((signature, bytes.len() - remaining.len()))
+    }
+
     /// Create a `Signature::Structure` for a given set of field signatures.
+    ///
+    /// The fields are taken as is. In particular, `Signature::Unit` fields are kept, since they
+    /// stand for `()` fields of a tuple, which are still (de)serialized, even though they encode no
+    /// data. Note that an empty `fields` gives an empty structure, whose string form (`()`) is not a
+    /// valid D-Bus signature. Use [`Signature::from_fields`] to get a valid signature from any list
+    /// of fields.
     pub fn structure<F>(fields: F) -> Self
     where
         F: Into<FieldsSignatures>,
@@ -159,11 +816,195 @@ impl Signature {
         Signature::Structure(fields.into())
     }
 
+    /// Create a signature from a list of field signatures.
+    ///
+    /// This follows the same rules as parsing a list of types on the top-level of a signature:
+    /// no fields result in `Signature::Unit`, a single field results in the field's signature
+    /// itself and more than one field result in a `Signature::Structure`. `Signature::Unit` fields
+    /// are treated as "no field" and skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::from_fields([]), "");
+    /// assert_eq!(Signature::from_fields([Signature::Str]), "s");
+    /// assert_eq!(Signature::from_fields([Signature::Str, Signature::U32]), "(su)");
+    /// assert_eq!(Signature::from_fields([Signature::Unit, Signature::U32]), "u");
+    /// ```
+    pub fn from_fields<I>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = Signature>,
+    {
+        let mut fields = fields.into_iter().filter(|f| !f.is_empty());
+        let Some(first) = fields.next() else {
+            return Signature::Unit;
+        };
+        let Some(second) = fields.next() else {
+            return first;
+        };
+
+        let fields: Vec<_> = [first, second].into_iter().chain(fields).collect();
+        Signature::structure(fields)
+    }
+
     /// Create a `Signature::Structure` for a given set of static field signatures.
     pub const fn static_structure(fields: &'static [&'static Signature]) -> Self {
         Signature::Structure(FieldsSignatures::Static { fields })
     }
 
+    /// Concatenate `self` and `other` into a `Signature::Structure`.
+    ///
+    /// Just like a list of types on the top-level of a D-Bus signature, structure operands are
+    /// flattened so their fields become fields of the resulting structure, and `Signature::Unit`
+    /// operands contribute no fields at all. If neither operand contributes any fields, the result
+    /// is `Signature::Unit`, since an empty structure is not a valid signature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let sig = Signature::from_str("(ss)").unwrap().concat(Signature::I32);
+    /// assert_eq!(sig, "(ssi)");
+    ///
+    /// let sig = Signature::Str.concat(Signature::from_str("a{sv}").unwrap());
+    /// assert_eq!(sig, "(sa{sv})");
+    /// ```
+    pub fn concat(self, other: Signature) -> Signature {
+        let mut fields = Vec::with_capacity(self.n_fields().max(1) + other.n_fields().max(1));
+        for signature in [self, other] {
+            match signature {
+                Signature::Unit => (),
+                Signature::Structure(_) => fields.extend(signature.fields().cloned()),
+                _ => fields.push(signature),
+            }
+        }
+        if fields.is_empty() {
+            return Signature::Unit;
+        }
+
+        Signature::structure(fields)
+    }
+
+    /// Strip redundant single-field structure wrapping from `self`.
+    ///
+    /// If `self` is a [`Signature::Structure`] with exactly one field, that field is returned
+    /// instead, and so on recursively. Any other signature is returned as is.
+    ///
+    /// Note that this is lossy: `((i))` and `i` are distinct types per the D-Bus specification, so
+    /// only use this for normalizing signatures for comparison. Also, only the top level is
+    /// flattened; single-field structures inside containers (e.g `a(i)`) are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::from_str("((i))").unwrap().top_level_flatten(), "i");
+    /// assert_eq!(Signature::from_str("((ii))").unwrap().top_level_flatten(), "(ii)");
+    /// assert_eq!(Signature::from_str("(a(i))").unwrap().top_level_flatten(), "a(i)");
+    /// ```
+    pub fn top_level_flatten(self) -> Signature {
+        let mut signature = self;
+        // `n_fields` is only non-zero for structures.
+        while signature.n_fields() == 1 {
+            let field = signature.fields().next().cloned();
+            match field {
+                Some(field) => signature = field,
+                None => break,
+            }
+        }
+
+        signature
+    }
+
+    /// Creates a clone of `self` that doesn't refer to any static signatures.
+    ///
+    /// All [`ChildSignature::Static`] and [`FieldsSignatures::Static`] in the signature tree are
+    /// recursively converted into their dynamic counterparts. This is useful for normalizing
+    /// signatures built from mixed sources before storing them in long-lived structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zvariant::parsed::{ChildSignature, Signature};
+    ///
+    /// const ARRAY: Signature = Signature::static_array(&Signature::Str);
+    /// let owned = ARRAY.to_owned();
+    /// assert!(matches!(owned, Signature::Array(ChildSignature::Dynamic { .. })));
+    /// assert_eq!(owned, ARRAY);
+    /// ```
+    pub fn to_owned(&self) -> Signature {
+        match self {
+            Signature::Array(child) => Signature::array(child.signature().to_owned()),
+            Signature::Dict { key, value } => {
+                Signature::dict(key.signature().to_owned(), value.signature().to_owned())
+            }
+            Signature::Structure(fields) => {
+                Signature::structure(fields.iter().map(Signature::to_owned).collect::<Vec<_>>())
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(child) => Signature::maybe(child.signature().to_owned()),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => {
+                Signature::dict_entry(key.signature().to_owned(), value.signature().to_owned())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Creates a clone of `self` that doesn't refer to any static signatures.
+    ///
+    /// See [`Signature::to_owned`] for details.
+    pub fn into_owned(self) -> Signature {
+        self.to_owned()
+    }
+
+    /// The longest common structural prefix of `self` and `other`.
+    ///
+    /// For two [`Signature::Structure`]s, this is a structure of their leading fields that are
+    /// equal, stopping at the first field that differs. For any other signatures, this is the
+    /// signature itself if both are equal. If nothing is shared, `Signature::Unit` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let a = Signature::from_str("(ssi)").unwrap();
+    /// let b = Signature::from_str("(ssu)").unwrap();
+    /// assert_eq!(a.common_prefix(&b), "(ss)");
+    ///
+    /// let c = Signature::from_str("(iss)").unwrap();
+    /// assert_eq!(a.common_prefix(&c), "");
+    ///
+    /// assert_eq!(Signature::Str.common_prefix(&Signature::Str), "s");
+    /// ```
+    pub fn common_prefix(&self, other: &Signature) -> Signature {
+        match (self, other) {
+            (Signature::Structure(fields), Signature::Structure(other_fields)) => {
+                let prefix: Vec<_> = fields
+                    .iter()
+                    .zip(other_fields.iter())
+                    .take_while(|(field, other_field)| field == other_field)
+                    .map(|(field, _)| field.clone())
+                    .collect();
+                if prefix.is_empty() {
+                    Signature::Unit
+                } else {
+                    Signature::structure(prefix)
+                }
+            }
+            _ if self == other => self.clone(),
+            _ => Signature::Unit,
+        }
+    }
+
     /// Create a `Signature::Array` for a given child signature.
     pub fn array<C>(child: C) -> Self
     where
@@ -212,6 +1053,50 @@ impl Signature {
         Signature::Maybe(ChildSignature::Static { child })
     }
 
+    /// Create a `Signature::DictEntry` for a given key and value signatures.
+    #[cfg(feature = "gvariant")]
+    pub fn dict_entry<K, V>(key: K, value: V) -> Self
+    where
+        K: Into<ChildSignature>,
+        V: Into<ChildSignature>,
+    {
+        Signature::DictEntry {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Create a `Signature::DictEntry` for a given static key and value signatures.
+    #[cfg(feature = "gvariant")]
+    pub const fn static_dict_entry(key: &'static Signature, value: &'static Signature) -> Self {
+        Signature::DictEntry {
+            key: ChildSignature::Static { child: key },
+            value: ChildSignature::Static { child: value },
+        }
+    }
+
+    /// The required padding alignment for the given format.
+    ///
+    /// Since D-Bus doesn't have a maybe type, the alignment of a [`Signature::Maybe`] for
+    /// [`Format::DBus`] is that of an array, which is also how `Option<T>` is encoded in D-Bus
+    /// when the `option-as-array` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::{parsed::Signature, serialized::Format};
+    ///
+    /// let sig = Signature::from_str("(ys)").unwrap();
+    /// assert_eq!(sig.alignment_for(Format::DBus), 8);
+    ///
+    /// let sig = Signature::from_str("ay").unwrap();
+    /// assert_eq!(sig.alignment_for(Format::DBus), 4);
+    /// ```
+    pub fn alignment_for(&self, format: Format) -> usize {
+        self.alignment(format)
+    }
+
     /// The required padding alignment for the given format.
     pub(crate) fn alignment(&self, format: Format) -> usize {
         match format {
@@ -232,15 +1117,17 @@ impl Signature {
             | Signature::ObjectPath
             | Signature::Array(_)
             | Signature::Dict { .. } => 4,
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(_) => 4,
             Signature::I64
             | Signature::U64
             | Signature::F64
             | Signature::Unit
             | Signature::Structure(_) => 8,
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { .. } => 8,
             #[cfg(unix)]
             Signature::Fd => 4,
-            #[cfg(feature = "gvariant")]
-            Signature::Maybe(_) => unreachable!("Maybe type is not supported in D-Bus"),
         }
     }
 
@@ -265,7 +1152,7 @@ impl Signature {
             Signature::Str | Signature::ObjectPath => 1,
             Signature::Variant => 8,
             Signature::Array(child) | Signature::Maybe(child) => child.alignment_gvariant(),
-            Signature::Dict { key, value } => {
+            Signature::Dict { key, value } | Signature::DictEntry { key, value } => {
                 max(key.alignment_gvariant(), value.alignment_gvariant())
             }
             Signature::Structure(fields) => fields
@@ -276,8 +1163,21 @@ impl Signature {
         }
     }
 
-    #[cfg(feature = "gvariant")]
-    pub(crate) fn is_fixed_sized(&self) -> bool {
+    /// Whether values of this type always have the same encoded size.
+    ///
+    /// This is the case for basic types other than strings, signatures, object paths and variants,
+    /// and for structures made up exclusively of fixed-sized fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert!(Signature::from_str("(yyy)").unwrap().is_fixed_sized());
+    /// assert!(!Signature::from_str("(ys)").unwrap().is_fixed_sized());
+    /// ```
+    pub fn is_fixed_sized(&self) -> bool {
         match self {
             Signature::Unit
             | Signature::U8
@@ -296,9 +1196,144 @@ impl Signature {
             | Signature::ObjectPath
             | Signature::Variant
             | Signature::Array(_)
-            | Signature::Dict { .. }
-            | Signature::Maybe(_) => false,
+            | Signature::Dict { .. } => false,
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(_) => false,
             Signature::Structure(fields) => fields.iter().all(|f| f.is_fixed_sized()),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => key.is_fixed_sized() && value.is_fixed_sized(),
+        }
+    }
+
+    /// The minimum size of an encoded value of this type, in the given format.
+    ///
+    /// This is the size of the smallest possible value (e.g empty strings and arrays) when encoded
+    /// at offset 0, including any padding required within the value. It's useful for
+    /// pre-allocating serialization buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::{parsed::Signature, serialized::Format};
+    ///
+    /// assert_eq!(Signature::U32.min_encoded_size(Format::DBus), 4);
+    /// // Length prefix and the trailing nul byte.
+    /// assert_eq!(Signature::Str.min_encoded_size(Format::DBus), 5);
+    /// // A byte, padding to 4, and an empty string.
+    /// let sig = Signature::from_str("(ys)").unwrap();
+    /// assert_eq!(sig.min_encoded_size(Format::DBus), 9);
+    /// ```
+    pub fn min_encoded_size(&self, format: Format) -> usize {
+        self.min_encoded_end(0, format)
+    }
+
+    /// The encoded size of values of this type in the GVariant format, if it's fixed.
+    ///
+    /// Returns `None` for variable-sized types (see [`Signature::is_fixed_sized`]). For fixed-sized
+    /// types, this is the exact size of any value, including the padding within it and at the end
+    /// of structures. It's needed to compute the layout of GVariant containers (e.g whether framing
+    /// offsets are needed) without a value in hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// assert_eq!(Signature::U32.gvariant_fixed_size(), Some(4));
+    /// // A byte, padding to 4, the `u`, and padding to the alignment of the structure.
+    /// assert_eq!(Signature::from_str("(yu)").unwrap().gvariant_fixed_size(), Some(8));
+    /// assert_eq!(Signature::from_str("(ys)").unwrap().gvariant_fixed_size(), None);
+    /// ```
+    #[cfg(feature = "gvariant")]
+    pub fn gvariant_fixed_size(&self) -> Option<usize> {
+        // For fixed-sized types, the smallest value is as big as any other.
+        self.is_fixed_sized()
+            .then(|| self.min_encoded_size(Format::GVariant))
+    }
+
+    // The offset right after the smallest possible value of this type, encoded at `offset`.
+    fn min_encoded_end(&self, offset: usize, format: Format) -> usize {
+        let start = offset + padding_for_n_bytes(offset, self.alignment(format));
+
+        match format {
+            Format::DBus => match self {
+                // No data and hence no padding either.
+                Signature::Unit => offset,
+                Signature::U8 => start + 1,
+                Signature::I16 | Signature::U16 => start + 2,
+                Signature::Bool | Signature::I32 | Signature::U32 => start + 4,
+                #[cfg(unix)]
+                Signature::Fd => start + 4,
+                Signature::I64 | Signature::U64 | Signature::F64 => start + 8,
+                // Length, followed by the nul byte.
+                Signature::Str | Signature::ObjectPath => start + 5,
+                Signature::Signature => start + 2,
+                // The signature of the smallest value (`y`), followed by the value.
+                Signature::Variant => Signature::U8.min_encoded_end(start + 3, format),
+                // Length, followed by the padding for the first element.
+                Signature::Array(child) => {
+                    let end = start + 4;
+                    end + padding_for_n_bytes(end, child.alignment(format))
+                }
+                Signature::Dict { .. } => {
+                    let end = start + 4;
+                    end + padding_for_n_bytes(end, 8)
+                }
+                #[cfg(feature = "gvariant")]
+                Signature::Maybe(child) => {
+                    let end = start + 4;
+                    end + padding_for_n_bytes(end, child.alignment(format))
+                }
+                Signature::Structure(fields) => fields
+                    .iter()
+                    .fold(start, |end, field| field.min_encoded_end(end, format)),
+                #[cfg(feature = "gvariant")]
+                Signature::DictEntry { key, value } => {
+                    value.min_encoded_end(key.min_encoded_end(start, format), format)
+                }
+            },
+            #[cfg(feature = "gvariant")]
+            Format::GVariant => match self {
+                // No data and hence no padding either.
+                Signature::Unit => offset,
+                Signature::U8 | Signature::Bool => start + 1,
+                Signature::I16 | Signature::U16 => start + 2,
+                Signature::I32 | Signature::U32 => start + 4,
+                #[cfg(unix)]
+                Signature::Fd => start + 4,
+                Signature::I64 | Signature::U64 | Signature::F64 => start + 8,
+                // Just the nul byte.
+                Signature::Str | Signature::ObjectPath | Signature::Signature => start + 1,
+                // The smallest value (a byte), a nul byte and its signature (`y`).
+                Signature::Variant => start + 3,
+                // Empty arrays and `Nothing` take no space at all.
+                Signature::Array(_) | Signature::Dict { .. } | Signature::Maybe(_) => start,
+                Signature::Structure(fields) => {
+                    let mut end = start;
+                    let mut n_offsets = 0;
+                    let mut fields = fields.iter().peekable();
+                    while let Some(field) = fields.next() {
+                        end = field.min_encoded_end(end, format);
+                        // Each variable-sized field, except the last one, needs a framing offset.
+                        if !field.is_fixed_sized() && fields.peek().is_some() {
+                            n_offsets += 1;
+                        }
+                    }
+                    if self.is_fixed_sized() {
+                        // Fixed-sized structures are padded to their alignment.
+                        end += padding_for_n_bytes(end - start, self.alignment(format));
+                    }
+
+                    end + n_offsets
+                }
+                // Encoded just like a structure with two fields.
+                Signature::DictEntry { key, value } => {
+                    Signature::structure([(**key).clone(), (**value).clone()])
+                        .min_encoded_end(offset, format)
+                }
+            },
         }
     }
 
@@ -322,38 +1357,245 @@ impl Signature {
             Signature::Fd => write!(w, "h"),
             Signature::Array(array) => write!(w, "a{}", **array),
             Signature::Dict { key, value } => {
-                write!(w, "a{{")?;
-                write!(w, "{}{}", **key, **value)?;
-                write!(w, "}}")
+                write!(w, "a{{")?;
+                write!(w, "{}{}", **key, **value)?;
+                write!(w, "}}")
+            }
+            Signature::Structure(fields) => {
+                if outer_parens {
+                    write!(w, "(")?;
+                }
+                for field in fields.iter() {
+                    write!(w, "{}", field)?;
+                }
+                if outer_parens {
+                    write!(w, ")")?;
+                }
+
+                Ok(())
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(maybe) => write!(w, "m{}", **maybe),
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => write!(w, "{{{}{}}}", **key, **value),
+        }
+    }
+
+    fn write_as_bytes(&self, out: &mut Vec<u8>, outer_parens: bool) {
+        match self {
+            Signature::Unit => (),
+            Signature::U8 => out.push(b'y'),
+            Signature::Bool => out.push(b'b'),
+            Signature::I16 => out.push(b'n'),
+            Signature::U16 => out.push(b'q'),
+            Signature::I32 => out.push(b'i'),
+            Signature::U32 => out.push(b'u'),
+            Signature::I64 => out.push(b'x'),
+            Signature::U64 => out.push(b't'),
+            Signature::F64 => out.push(b'd'),
+            Signature::Str => out.push(b's'),
+            Signature::Signature => out.push(b'g'),
+            Signature::ObjectPath => out.push(b'o'),
+            Signature::Variant => out.push(b'v'),
+            #[cfg(unix)]
+            Signature::Fd => out.push(b'h'),
+            Signature::Array(array) => {
+                out.push(b'a');
+                array.write_as_bytes(out, true);
+            }
+            Signature::Dict { key, value } => {
+                out.extend_from_slice(b"a{");
+                key.write_as_bytes(out, true);
+                value.write_as_bytes(out, true);
+                out.push(b'}');
+            }
+            Signature::Structure(fields) => {
+                if outer_parens {
+                    out.push(b'(');
+                }
+                for field in fields.iter() {
+                    field.write_as_bytes(out, true);
+                }
+                if outer_parens {
+                    out.push(b')');
+                }
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::Maybe(maybe) => {
+                out.push(b'm');
+                maybe.write_as_bytes(out, true);
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => {
+                out.push(b'{');
+                key.write_as_bytes(out, true);
+                value.write_as_bytes(out, true);
+                out.push(b'}');
+            }
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Signature {
+    type Item = &'a Signature;
+    type IntoIter = Iter<'a>;
+
+    /// An iterator over the top-level types of the signature.
+    ///
+    /// This provides an "argument list" view of a signature, similar to how D-Bus message bodies
+    /// are structured: a [`Signature::Structure`] yields each of its fields, [`Signature::Unit`]
+    /// yields nothing and all other signatures yield themselves, once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use zvariant::parsed::Signature;
+    ///
+    /// let sig = Signature::from_str("ias").unwrap();
+    /// let args: Vec<_> = sig.into_iter().map(|s| s.to_string()).collect();
+    /// assert_eq!(args, ["i", "as"]);
+    ///
+    /// let sig = Signature::from_str("a{sv}").unwrap();
+    /// let args: Vec<_> = sig.into_iter().map(|s| s.to_string()).collect();
+    /// assert_eq!(args, ["a{sv}"]);
+    ///
+    /// assert_eq!(Signature::Unit.into_iter().count(), 0);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self {
+            Signature::Unit => IterInner::Single(None),
+            Signature::Structure(FieldsSignatures::Static { fields }) => {
+                IterInner::Static(fields.iter())
+            }
+            Signature::Structure(FieldsSignatures::Dynamic { fields }) => {
+                IterInner::Dynamic(fields.iter())
+            }
+            sig => IterInner::Single(Some(sig)),
+        };
+
+        Iter { inner }
+    }
+}
+
+/// An iterator over the top-level types of a [`Signature`].
+///
+/// See the `IntoIterator` implementation of `&Signature` for details.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    inner: IterInner<'a>,
+}
+
+#[derive(Debug, Clone)]
+enum IterInner<'a> {
+    Static(std::slice::Iter<'static, &'static Signature>),
+    Dynamic(std::slice::Iter<'a, Signature>),
+    Single(Option<&'a Signature>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Signature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IterInner::Static(iter) => iter.next().copied(),
+            IterInner::Dynamic(iter) => iter.next(),
+            IterInner::Single(sig) => sig.take(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            IterInner::Static(iter) => iter.size_hint(),
+            IterInner::Dynamic(iter) => iter.size_hint(),
+            IterInner::Single(sig) => {
+                let len = usize::from(sig.is_some());
+                (len, Some(len))
+            }
+        }
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+/// The alternate form (`{:#}`) writes an indented, multi-line tree representation of the
+/// signature, which is easier to read for large signatures.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use zvariant::parsed::Signature;
+///
+/// let sig = Signature::from_str("a{s(uas)}").unwrap();
+/// assert_eq!(format!("{sig}"), "a{s(uas)}");
+/// assert_eq!(
+///     format!("{sig:#}"),
+///     "dict:\n  key: s\n  value: struct:\n    u\n    array of:\n      s",
+/// );
+/// ```
+impl Display for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.write_as_tree(f, 0, "");
+        }
+
+        self.write_as_string(f, true)
+    }
+}
+
+impl Signature {
+    // Write `self` as a tree, with each container's children on their own lines, indented further.
+    fn write_as_tree(&self, f: &mut Formatter<'_>, indent: usize, label: &str) -> fmt::Result {
+        write!(f, "{:indent$}{label}", "")?;
+
+        let indent = indent + 2;
+        match self {
+            Signature::Unit => f.write_str("unit"),
+            Signature::Array(child) => {
+                f.write_str("array of:\n")?;
+                child.write_as_tree(f, indent, "")
+            }
+            Signature::Dict { key, value } => {
+                f.write_str("dict:\n")?;
+                key.write_as_tree(f, indent, "key: ")?;
+                f.write_str("\n")?;
+                value.write_as_tree(f, indent, "value: ")
             }
             Signature::Structure(fields) => {
-                if outer_parens {
-                    write!(w, "(")?;
-                }
+                f.write_str("struct:")?;
                 for field in fields.iter() {
-                    write!(w, "{}", field)?;
-                }
-                if outer_parens {
-                    write!(w, ")")?;
+                    f.write_str("\n")?;
+                    field.write_as_tree(f, indent, "")?;
                 }
 
                 Ok(())
             }
             #[cfg(feature = "gvariant")]
-            Signature::Maybe(maybe) => write!(w, "m{}", **maybe),
+            Signature::Maybe(child) => {
+                f.write_str("maybe of:\n")?;
+                child.write_as_tree(f, indent, "")
+            }
+            #[cfg(feature = "gvariant")]
+            Signature::DictEntry { key, value } => {
+                f.write_str("dict entry:\n")?;
+                key.write_as_tree(f, indent, "key: ")?;
+                f.write_str("\n")?;
+                value.write_as_tree(f, indent, "value: ")
+            }
+            _ => self.write_as_string(f, true),
         }
     }
 }
 
-impl Display for Signature {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.write_as_string(f, true)
-    }
-}
-
+/// # Panics
+///
+/// If `value` is not a valid signature. This can only happen if `value` was created through one of
+/// the `_unchecked` constructors of [`crate::Signature`]. Use [`Signature::from_signature`] if
+/// that's a possibility.
 impl From<crate::Signature<'_>> for Signature {
     fn from(value: crate::Signature<'_>) -> Self {
-        Self::from_str(value.as_str()).expect("valid signature")
+        Self::from_signature(&value).expect("valid signature")
     }
 }
 
@@ -397,138 +1639,677 @@ impl FromStr for Signature {
     }
 }
 
+impl TryFrom<&[u8]> for Signature {
+    type Error = crate::Error;
+
+    fn try_from(value: &[u8]) -> crate::Result<Self> {
+        Signature::from_bytes(value)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Signature {
+    type Error = crate::Error;
+
+    fn try_from(value: Vec<u8>) -> crate::Result<Self> {
+        Signature::from_bytes(&value)
+    }
+}
+
 /// Validate the given signature string.
 pub fn validate(bytes: &[u8]) -> crate::Result<()> {
     parse(bytes, true).map(|_| ())
 }
 
+/// Validate the given signature string, returning a [`SignatureError`] on failure.
+///
+/// This is the same as [`validate`], except that the error keeps the input and the position of
+/// the error, for rendering a diagnostic to the user.
+pub fn validate_diagnostic(bytes: &[u8]) -> Result<(), SignatureError> {
+    parse_at(bytes, true)
+        .map(|_| ())
+        .map_err(|(e, offset)| SignatureError::new(bytes, offset, e))
+}
+
+/// Validate the given signature string, with stricter limits than the D-Bus specification.
+///
+/// This is useful for validating untrusted signatures. In addition to the checks done by
+/// [`validate`], the signature must be at most `max_len` bytes long, and must not nest containers
+/// more than `max_depth` levels deep (see [`Signature::depth`]).
+///
+/// Returns [`Error::SignatureTooLong`] or [`Error::SignatureTooDeep`], respectively, if one of
+/// these limits is exceeded. The length is checked before any parsing is done. Just like
+/// [`validate`], this doesn't build the parsed signature.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::{parsed::signature::validate_with_limits, Error};
+///
+/// assert!(validate_with_limits(b"a{sv}", 16, 1).is_ok());
+/// assert_eq!(
+///     validate_with_limits(b"a{sv}", 4, 1).unwrap_err(),
+///     Error::SignatureTooLong(4),
+/// );
+/// assert!(matches!(
+///     validate_with_limits(b"aas", 16, 1).unwrap_err(),
+///     Error::SignatureTooDeep(_),
+/// ));
+/// ```
+///
+/// [`Error::SignatureTooLong`]: crate::Error::SignatureTooLong
+/// [`Error::SignatureTooDeep`]: crate::Error::SignatureTooDeep
+pub fn validate_with_limits(bytes: &[u8], max_len: usize, max_depth: usize) -> crate::Result<()> {
+    if bytes.len() > max_len {
+        return Err(crate::Error::SignatureTooLong(max_len));
+    }
+
+    parse_nom(bytes, true, Nesting::new(max_depth))
+        .map(|_| ())
+        .map_err(|(e, _)| e)
+}
+
+/// Validate and parse the given signature string.
+///
+/// In addition to the parsed `Signature`, this returns its canonical string form. A single
+/// complete type stays as is, while a list of multiple complete types becomes a structure.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::parsed::{signature::parse_validated, Signature};
+///
+/// let (sig, canonical) = parse_validated(b"s").unwrap();
+/// assert_eq!(sig, Signature::Str);
+/// assert_eq!(canonical, "s");
+///
+/// let (sig, canonical) = parse_validated(b"sa{sv}").unwrap();
+/// assert_eq!(sig.n_fields(), 2);
+/// assert_eq!(canonical, "(sa{sv})");
+///
+/// assert!(parse_validated(b"a{sv").is_err());
+/// ```
+pub fn parse_validated(bytes: &[u8]) -> crate::Result<(Signature, String)> {
+    let signature = parse(bytes, false)?;
+    let canonical = signature.to_string();
+
+    Ok((signature, canonical))
+}
+
+/// Validate the given signature string and count the number of complete types in it.
+///
+/// This doesn't allocate and is useful when you only need to know how many top-level types a
+/// signature contains (e.g the number of arguments of a method).
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::parsed::signature::count_types;
+///
+/// assert_eq!(count_types(b"").unwrap(), 0);
+/// assert_eq!(count_types(b"a{sv}").unwrap(), 1);
+/// assert_eq!(count_types(b"sa{sv}as").unwrap(), 3);
+/// assert_eq!(count_types(b"(sa{sv}as)").unwrap(), 1);
+/// assert!(count_types(b"sa{sv").is_err());
+/// ```
+pub fn count_types(bytes: &[u8]) -> crate::Result<usize> {
+    let empty = map(eof, |_| 0);
+    let nesting = Nesting::default();
+    let (_, count) = all_consuming(alt((empty, move |s| count(s, nesting))))(bytes)
+        .map_err(|e| parse_error(bytes, e))?;
+
+    Ok(count)
+}
+
+/// Validate the given signature string and split it into its complete types.
+///
+/// Each complete type is returned in its string form, in order. This is useful for matching the
+/// top-level types of a signature (e.g the arguments of a method) against per-type expectations.
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::parsed::signature::split_complete_types;
+///
+/// assert_eq!(split_complete_types(b"sia{sv}").unwrap(), ["s", "i", "a{sv}"]);
+/// assert_eq!(split_complete_types(b"(sa{sv}as)").unwrap(), ["(sa{sv}as)"]);
+/// assert!(split_complete_types(b"").unwrap().is_empty());
+/// assert!(split_complete_types(b"sa{sv").is_err());
+/// ```
+pub fn split_complete_types(bytes: &[u8]) -> crate::Result<Vec<String>> {
+    let empty = map(eof, |_| vec![]);
+    let nesting = Nesting::default();
+    let (_, types) = all_consuming(alt((empty, move |s| split(s, nesting))))(bytes)
+        .map_err(|e| parse_error(bytes, e))?;
+
+    Ok(types
+        .into_iter()
+        // The parser only accepts ASCII characters.
+        .map(|t| t.iter().copied().map(char::from).collect())
+        .collect())
+}
+
 /// Parse a signature string into a `Signature`.
 ///
 /// When `check_only` is true, the function will not allocate memory for the dynamic types.
 /// Instead it will return dummy values in the parsed Signature.
 fn parse(bytes: &[u8], check_only: bool) -> crate::Result<Signature> {
-    use nom::{
-        branch::alt,
-        combinator::{all_consuming, eof, map},
-        multi::{many1, many1_count},
-        sequence::{delimited, pair},
-    };
+    parse_at(bytes, check_only).map_err(|(e, _)| e)
+}
+
+/// Same as [`parse`], but on failure, also returns the position in `bytes` at which the error
+/// was detected.
+fn parse_at(bytes: &[u8], check_only: bool) -> Result<Signature, (crate::Error, usize)> {
+    if !check_only {
+        // The iterative parser is faster but it doesn't report why parsing failed. Parsing
+        // failures should be rare so we just fall back to the nom-based parser for the error.
+        if let Some(signature) = parse_iterative(bytes) {
+            return Ok(signature);
+        }
+    }
 
+    parse_nom(bytes, check_only, Nesting::default())
+}
+
+/// Parse a signature string into a `Signature`, using the nom-based parser.
+///
+/// `nesting` sets the maximum nesting level of containers, in addition to the limits of the
+/// specification.
+fn parse_nom(
+    bytes: &[u8],
+    check_only: bool,
+    nesting: Nesting,
+) -> Result<Signature, (crate::Error, usize)> {
     let empty = map(eof, |_| Signature::Unit);
+    let (_, signature) =
+        all_consuming(alt((empty, move |s| many(s, check_only, true, nesting))))(bytes)
+            .map_err(|e| parse_error_at(bytes, e))?;
+
+    Ok(signature)
+}
 
-    fn byte<'bytes, Error: nom::error::ParseError<&'bytes [u8]>>(
-        b: u8,
-    ) -> impl Fn(&'bytes [u8]) -> nom::IResult<&'bytes [u8], &'bytes [u8], Error> {
-        move |bytes: &'bytes [u8]| nom::bytes::complete::tag(&[b])(bytes)
+/// Parse a signature string into a `Signature`, without recursion.
+///
+/// This produces exactly the same `Signature` as the nom-based parser but instead of recursing
+/// for each container, it keeps the partially-built containers on an explicit stack. Returns
+/// `None` if `bytes` is not a valid signature.
+fn parse_iterative(bytes: &[u8]) -> Option<Signature> {
+    enum Container {
+        Array,
+        Dict {
+            key: Option<Signature>,
+            value: Option<Signature>,
+            // Whether the entry is wrapped in an array (`a{}`), i-e it's a dictionary rather than a
+            // standalone (GVariant) dict-entry.
+            array: bool,
+        },
+        Structure(Vec<Signature>),
+        #[cfg(feature = "gvariant")]
+        Maybe,
     }
 
-    // `many1` allocates so we only want to use it when `check_only == false`
-    type ManyError<'b> = nom::Err<nom::error::Error<&'b [u8]>>;
-    fn many(
-        bytes: &[u8],
-        check_only: bool,
-        top_level: bool,
-    ) -> Result<(&[u8], Signature), ManyError<'_>> {
-        let parser = |s| parse_signature(s, check_only);
-        if check_only {
-            return map(many1_count(parser), |_| Signature::Unit)(bytes);
-        }
-
-        map(many1(parser), |mut signatures| {
-            if top_level {
-                // On the top-level, we want to return:
-                //
-                // * unit signature if there are none.
-                // * the signature directly if there is only one.
-                if signatures.is_empty() {
-                    return Signature::Unit;
-                } else if signatures.len() == 1 {
-                    return signatures.remove(0);
+    let mut stack: Vec<(Container, ContainerDepths)> = Vec::new();
+    let mut top_level = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let depths = stack.last().map(|(_, d)| *d).unwrap_or_default();
+        let mut signature = match bytes[i] {
+            b'a' if bytes.get(i + 1) == Some(&b'{') => {
+                let depths = depths.inc_array().and_then(|d| d.inc_structure()).ok()?;
+                let dict = Container::Dict {
+                    key: None,
+                    value: None,
+                    array: true,
+                };
+                stack.push((dict, depths));
+                i += 2;
+
+                continue;
+            }
+            #[cfg(feature = "gvariant")]
+            b'{' => {
+                let entry = Container::Dict {
+                    key: None,
+                    value: None,
+                    array: false,
+                };
+                stack.push((entry, depths.inc_structure().ok()?));
+                i += 1;
+
+                continue;
+            }
+            b'a' => {
+                stack.push((Container::Array, depths.inc_array().ok()?));
+                i += 1;
+
+                continue;
+            }
+            b'(' => {
+                stack.push((
+                    Container::Structure(Vec::new()),
+                    depths.inc_structure().ok()?,
+                ));
+                i += 1;
+
+                continue;
+            }
+            #[cfg(feature = "gvariant")]
+            b'm' => {
+                stack.push((Container::Maybe, depths.inc_maybe().ok()?));
+                i += 1;
+
+                continue;
+            }
+            b')' => match stack.pop()? {
+                (Container::Structure(fields), _) if !fields.is_empty() => {
+                    Signature::structure(fields)
+                }
+                _ => return None,
+            },
+            b'}' => match stack.pop()? {
+                (
+                    Container::Dict {
+                        key: Some(key),
+                        value: Some(value),
+                        array: true,
+                    },
+                    _,
+                ) => Signature::dict(key, value),
+                #[cfg(feature = "gvariant")]
+                (
+                    Container::Dict {
+                        key: Some(key),
+                        value: Some(value),
+                        array: false,
+                    },
+                    _,
+                ) => Signature::dict_entry(key, value),
+                _ => return None,
+            },
+            c => Signature::from_type_char(c as char)?,
+        };
+        i += 1;
+
+        // We've got a complete type, so add it to its parent container. If that completes the
+        // parent too (arrays and maybes only have a single child), keep going up the stack.
+        loop {
+            match stack.last_mut() {
+                None => {
+                    top_level.push(signature);
+                    break;
+                }
+                Some((Container::Structure(fields), _)) => {
+                    fields.push(signature);
+                    break;
+                }
+                Some((Container::Dict { key, value, .. }, _)) => {
+                    if key.is_none() {
+                        if !signature.is_basic() {
+                            // Let the nom parser report the error.
+                            return None;
+                        }
+                        *key = Some(signature);
+                    } else if value.is_none() {
+                        *value = Some(signature);
+                    } else {
+                        return None;
+                    }
+                    break;
+                }
+                Some((Container::Array, _)) => {
+                    stack.pop();
+                    signature = Signature::array(signature);
+                }
+                #[cfg(feature = "gvariant")]
+                Some((Container::Maybe, _)) => {
+                    stack.pop();
+                    signature = Signature::maybe(signature);
                 }
             }
+        }
+    }
 
-            Signature::structure(signatures)
-        })(bytes)
+    if !stack.is_empty() {
+        return None;
     }
 
-    fn parse_signature(bytes: &[u8], check_only: bool) -> nom::IResult<&[u8], Signature> {
-        let parse_with_context = |bytes| parse_signature(bytes, check_only);
+    let signature = match top_level.len() {
+        0 => Signature::Unit,
+        1 => top_level.remove(0),
+        _ => Signature::structure(top_level),
+    };
 
-        let simple_type = alt((
-            map(byte(b'y'), |_| Signature::U8),
-            map(byte(b'b'), |_| Signature::Bool),
-            map(byte(b'n'), |_| Signature::I16),
-            map(byte(b'q'), |_| Signature::U16),
-            map(byte(b'i'), |_| Signature::I32),
-            map(byte(b'u'), |_| Signature::U32),
-            map(byte(b'x'), |_| Signature::I64),
-            map(byte(b't'), |_| Signature::U64),
-            map(byte(b'd'), |_| Signature::F64),
-            map(byte(b's'), |_| Signature::Str),
-            map(byte(b'g'), |_| Signature::Signature),
-            map(byte(b'o'), |_| Signature::ObjectPath),
-            map(byte(b'v'), |_| Signature::Variant),
-            #[cfg(unix)]
-            map(byte(b'h'), |_| Signature::Fd),
-        ));
-
-        let dict = map(
-            pair(
-                byte(b'a'),
-                delimited(
-                    byte(b'{'),
-                    pair(parse_with_context, parse_with_context),
-                    byte(b'}'),
-                ),
-            ),
-            |(_, (key, value))| {
-                if check_only {
-                    return Signature::Dict {
-                        key: Signature::Unit.into(),
-                        value: Signature::Unit.into(),
-                    };
-                }
+    Some(signature)
+}
 
-                Signature::Dict {
-                    key: key.into(),
-                    value: value.into(),
-                }
-            },
-        );
+fn byte<'bytes, Error: nom::error::ParseError<&'bytes [u8]>>(
+    b: u8,
+) -> impl Fn(&'bytes [u8]) -> nom::IResult<&'bytes [u8], &'bytes [u8], Error> {
+    move |bytes: &'bytes [u8]| nom::bytes::complete::tag(&[b])(bytes)
+}
+
+// `many1` allocates so we only want to use it when `check_only == false`
+fn many(
+    bytes: &[u8],
+    check_only: bool,
+    top_level: bool,
+    nesting: Nesting,
+) -> nom::IResult<&[u8], Signature, ParseError<'_>> {
+    if check_only {
+        return map(|s| count(s, nesting), |_| Signature::Unit)(bytes);
+    }
+
+    let parser = |s| parse_signature(s, check_only, nesting);
+    map(many1(parser), |mut signatures| {
+        if top_level {
+            // On the top-level, we want to return:
+            //
+            // * unit signature if there are none.
+            // * the signature directly if there is only one.
+            if signatures.is_empty() {
+                return Signature::Unit;
+            } else if signatures.len() == 1 {
+                return signatures.remove(0);
+            }
+        }
+
+        Signature::structure(signatures)
+    })(bytes)
+}
+
+// Same as `many1_count` but unlike it, we keep the error from the first element so that the
+// failure position can be reported.
+fn count(bytes: &[u8], nesting: Nesting) -> nom::IResult<&[u8], usize, ParseError<'_>> {
+    let parser = |s| parse_signature(s, true, nesting);
+    let (mut bytes, _) = parser(bytes)?;
+    let mut count = 1;
+    loop {
+        match parser(bytes) {
+            Ok((rest, _)) => {
+                bytes = rest;
+                count += 1;
+            }
+            Err(nom::Err::Error(_)) => return Ok((bytes, count)),
+            Err(e) => return Err(e),
+        }
+    }
+}
 
-        let array = map(pair(byte(b'a'), parse_with_context), |(_, child)| {
+fn split(bytes: &[u8], nesting: Nesting) -> nom::IResult<&[u8], Vec<&[u8]>, ParseError<'_>> {
+    let mut types = vec![];
+    let mut remaining = bytes;
+    loop {
+        match parse_signature(remaining, true, nesting) {
+            Ok((rest, _)) => {
+                types.push(&remaining[..remaining.len() - rest.len()]);
+                remaining = rest;
+            }
+            Err(nom::Err::Error(_)) if !types.is_empty() => return Ok((remaining, types)),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn parse_signature(
+    bytes: &[u8],
+    check_only: bool,
+    nesting: Nesting,
+) -> nom::IResult<&[u8], Signature, ParseError<'_>> {
+    // Check the depth before recursing into a container so that deeply nested signatures
+    // can't overflow the stack.
+    let nesting = nesting.enter(bytes).map_err(|error| {
+        nom::Err::Failure(ParseError {
+            remaining: bytes,
+            expected: "a shallower container",
+            error: Some(error),
+        })
+    })?;
+
+    // Give a helpful error for GVariant-specific types if the `gvariant` feature is disabled.
+    #[cfg(not(feature = "gvariant"))]
+    if let [b'm', ..] = bytes {
+        return Err(nom::Err::Failure(ParseError {
+            remaining: bytes,
+            expected: "a D-Bus type",
+            error: Some(crate::Error::GVariantFeatureDisabled),
+        }));
+    }
+
+    // D-Bus doesn't allow empty structures. Say so, rather than complaining about the `)`.
+    if let [b'(', b')', ..] = bytes {
+        return Err(nom::Err::Failure(ParseError {
+            remaining: bytes,
+            expected: "a non-empty structure",
+            error: Some(crate::Error::EmptyStruct),
+        }));
+    }
+
+    let parse_with_context = move |bytes| parse_signature(bytes, check_only, nesting);
+
+    // D-Bus only allows basic types as dictionary keys.
+    let dict_key = move |bytes| {
+        let (rest, key) = parse_with_context(bytes)?;
+        if !key.is_basic() {
+            return Err(nom::Err::Failure(ParseError {
+                remaining: bytes,
+                expected: "a basic type",
+                error: Some(crate::Error::InvalidDictKey),
+            }));
+        }
+
+        Ok((rest, key))
+    };
+
+    let simple_type = alt((
+        map(byte(b'y'), |_| Signature::U8),
+        map(byte(b'b'), |_| Signature::Bool),
+        map(byte(b'n'), |_| Signature::I16),
+        map(byte(b'q'), |_| Signature::U16),
+        map(byte(b'i'), |_| Signature::I32),
+        map(byte(b'u'), |_| Signature::U32),
+        map(byte(b'x'), |_| Signature::I64),
+        map(byte(b't'), |_| Signature::U64),
+        map(byte(b'd'), |_| Signature::F64),
+        map(byte(b's'), |_| Signature::Str),
+        map(byte(b'g'), |_| Signature::Signature),
+        map(byte(b'o'), |_| Signature::ObjectPath),
+        map(byte(b'v'), |_| Signature::Variant),
+        #[cfg(unix)]
+        map(byte(b'h'), |_| Signature::Fd),
+    ));
+
+    let dict = map(
+        pair(
+            byte(b'a'),
+            delimited(byte(b'{'), pair(dict_key, parse_with_context), byte(b'}')),
+        ),
+        |(_, (key, value))| {
             if check_only {
-                return Signature::Array(Signature::Unit.into());
+                return Signature::Dict {
+                    key: Signature::Unit.into(),
+                    value: Signature::Unit.into(),
+                };
+            }
+
+            Signature::Dict {
+                key: key.into(),
+                value: value.into(),
             }
+        },
+    );
+
+    let array = map(pair(byte(b'a'), parse_with_context), |(_, child)| {
+        if check_only {
+            return Signature::Array(Signature::Unit.into());
+        }
 
-            Signature::Array(child.into())
-        });
+        Signature::Array(child.into())
+    });
 
-        let structure = delimited(byte(b'('), |s| many(s, check_only, false), byte(b')'));
+    let structure = delimited(
+        byte(b'('),
+        move |s| many(s, check_only, false, nesting),
+        byte(b')'),
+    );
 
-        #[cfg(feature = "gvariant")]
-        let maybe = map(pair(byte(b'm'), parse_with_context), |(_, child)| {
+    #[cfg(feature = "gvariant")]
+    let maybe = map(pair(byte(b'm'), parse_with_context), |(_, child)| {
+        if check_only {
+            return Signature::Maybe(Signature::Unit.into());
+        }
+
+        Signature::Maybe(child.into())
+    });
+
+    #[cfg(feature = "gvariant")]
+    let dict_entry = map(
+        delimited(byte(b'{'), pair(dict_key, parse_with_context), byte(b'}')),
+        |(key, value)| {
             if check_only {
-                return Signature::Maybe(Signature::Unit.into());
+                return Signature::DictEntry {
+                    key: Signature::Unit.into(),
+                    value: Signature::Unit.into(),
+                };
+            }
+
+            Signature::DictEntry {
+                key: key.into(),
+                value: value.into(),
             }
+        },
+    );
+
+    alt((
+        simple_type,
+        dict,
+        array,
+        structure,
+        #[cfg(feature = "gvariant")]
+        maybe,
+        #[cfg(feature = "gvariant")]
+        dict_entry,
+    ))(bytes)
+}
+
+/// The nesting of containers at the current position of the parser.
+#[derive(Debug, Clone, Copy)]
+struct Nesting {
+    depths: ContainerDepths,
+    // The number of containers we're in, as counted by `Signature::depth`, and its maximum.
+    level: usize,
+    max_level: usize,
+}
 
-            Signature::Maybe(child.into())
-        });
+impl Nesting {
+    fn new(max_level: usize) -> Self {
+        Nesting {
+            depths: ContainerDepths::default(),
+            level: 0,
+            max_level,
+        }
+    }
 
-        alt((
-            simple_type,
-            dict,
-            array,
-            structure,
+    /// Account for the container starting at `bytes`, if any.
+    fn enter(mut self, bytes: &[u8]) -> crate::Result<Self> {
+        let depths = match bytes {
+            [b'a', b'{', ..] => self.depths.inc_array().and_then(|d| d.inc_structure()),
+            [b'a', ..] => self.depths.inc_array(),
+            [b'(', ..] => self.depths.inc_structure(),
+            #[cfg(feature = "gvariant")]
+            [b'{', ..] => self.depths.inc_structure(),
             #[cfg(feature = "gvariant")]
-            maybe,
-        ))(bytes)
+            [b'm', ..] => self.depths.inc_maybe(),
+            _ => return Ok(self),
+        };
+        self.depths = depths.map_err(|e| match e {
+            crate::Error::MaxDepthExceeded(max) => crate::Error::SignatureTooDeep(max),
+            _ => unreachable!("container depth checks only return `MaxDepthExceeded`"),
+        })?;
+
+        self.level += 1;
+        if self.level > self.max_level {
+            return Err(crate::Error::SignatureTooDeep(
+                crate::MaxDepthExceeded::Container,
+            ));
+        }
+
+        Ok(self)
+    }
+}
+
+impl Default for Nesting {
+    fn default() -> Self {
+        Nesting::new(usize::MAX)
     }
+}
 
-    let (_, signature) = all_consuming(alt((empty, |s| many(s, check_only, true))))(bytes)
-        .map_err(|_| crate::Error::InvalidSignature)?;
+fn parse_error(bytes: &[u8], e: nom::Err<ParseError<'_>>) -> crate::Error {
+    parse_error_at(bytes, e).0
+}
 
-    Ok(signature)
+/// Same as [`parse_error`], but also returns the position in `bytes` at which the error was
+/// detected.
+fn parse_error_at(bytes: &[u8], e: nom::Err<ParseError<'_>>) -> (crate::Error, usize) {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            let position = bytes.len() - e.remaining.len();
+            let error = e.error.unwrap_or(crate::Error::SignatureParse {
+                position,
+                expected: e.expected,
+            });
+
+            (error, position)
+        }
+        nom::Err::Incomplete(_) => {
+            let position = bytes.len();
+            let error = crate::Error::SignatureParse {
+                position,
+                expected: "a complete type",
+            };
+
+            (error, position)
+        }
+    }
+}
+
+/// The error type of the signature parser.
+///
+/// Unlike `nom::error::Error`, this keeps the error that got the furthest into the input when
+/// alternatives fail, so that the reported position points at the offending character rather
+/// than at the start of the container that failed to parse.
+#[derive(Debug)]
+struct ParseError<'b> {
+    remaining: &'b [u8],
+    expected: &'static str,
+    // An error to report as is, instead of a `SignatureParse` error.
+    error: Option<crate::Error>,
+}
+
+impl<'b> nom::error::ParseError<&'b [u8]> for ParseError<'b> {
+    fn from_error_kind(remaining: &'b [u8], kind: nom::error::ErrorKind) -> Self {
+        let expected = match kind {
+            nom::error::ErrorKind::Eof => "end of signature",
+            nom::error::ErrorKind::Tag => "a valid signature character",
+            _ => "a complete type",
+        };
+
+        ParseError {
+            remaining,
+            expected,
+            error: None,
+        }
+    }
+
+    fn append(_: &'b [u8], _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn or(self, other: Self) -> Self {
+        if self.remaining.len() < other.remaining.len() {
+            self
+        } else {
+            other
+        }
+    }
 }
 
 impl PartialEq for Signature {
@@ -564,6 +2345,17 @@ impl PartialEq for Signature {
             (Signature::Structure(a), Signature::Structure(b)) => a.iter().eq(b.iter()),
             #[cfg(feature = "gvariant")]
             (Signature::Maybe(a), Signature::Maybe(b)) => a.eq(&**b),
+            #[cfg(feature = "gvariant")]
+            (
+                Signature::DictEntry {
+                    key: key_a,
+                    value: value_a,
+                },
+                Signature::DictEntry {
+                    key: key_b,
+                    value: value_b,
+                },
+            ) => key_a.eq(&**key_b) && value_a.eq(&**value_b),
             _ => false,
         }
     }
@@ -602,7 +2394,14 @@ impl PartialEq<&str> for Signature {
                     return false;
                 }
 
-                let (key_str, value_str) = other[2..other.len() - 1].split_at(1);
+                // `other` could contain non-ASCII characters.
+                let Some(entry_str) = other
+                    .get(2..other.len() - 1)
+                    .filter(|s| s.is_char_boundary(1))
+                else {
+                    return false;
+                };
+                let (key_str, value_str) = entry_str.split_at(1);
 
                 key.eq(key_str) && value.eq(value_str)
             }
@@ -616,7 +2415,7 @@ impl PartialEq<&str> for Signature {
 
                 let fields_str = if string_len == other.len() {
                     // `other` has to have outer `()`.
-                    if other.len() < 3 {
+                    if other.len() < 3 || !other.starts_with('(') || !other.ends_with(')') {
                         return false;
                     }
 
@@ -634,14 +2433,16 @@ impl PartialEq<&str> for Signature {
                 for field in fields.iter() {
                     let len = field.string_len();
                     let end = start + len;
-                    if !field.eq(&fields_str[start..end]) {
-                        return false;
+                    // `other` could be shorter than expected or contain non-ASCII characters.
+                    match fields_str.get(start..end) {
+                        Some(field_str) if field.eq(field_str) => (),
+                        _ => return false,
                     }
 
                     start += len;
                 }
 
-                true
+                start == fields_str.len()
             }
             #[cfg(feature = "gvariant")]
             Self::Maybe(child) => {
@@ -651,6 +2452,23 @@ impl PartialEq<&str> for Signature {
 
                 child.eq(&other[1..])
             }
+            #[cfg(feature = "gvariant")]
+            Self::DictEntry { key, value } => {
+                if other.len() < 3 || !other.starts_with('{') || !other.ends_with('}') {
+                    return false;
+                }
+
+                // `other` could contain non-ASCII characters.
+                let Some(entry_str) = other
+                    .get(1..other.len() - 1)
+                    .filter(|s| s.is_char_boundary(1))
+                else {
+                    return false;
+                };
+                let (key_str, value_str) = entry_str.split_at(1);
+
+                key.eq(key_str) && value.eq(value_str)
+            }
         }
     }
 }
@@ -673,6 +2491,18 @@ impl PartialEq<crate::OwnedSignature> for Signature {
     }
 }
 
+impl PartialEq<Signature> for crate::Signature<'_> {
+    fn eq(&self, other: &Signature) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialEq<Signature> for crate::OwnedSignature {
+    fn eq(&self, other: &Signature) -> bool {
+        other.eq(self)
+    }
+}
+
 impl PartialOrd for Signature {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -715,6 +2545,20 @@ impl Ord for Signature {
             (Signature::Structure(a), Signature::Structure(b)) => a.iter().cmp(b.iter()),
             #[cfg(feature = "gvariant")]
             (Signature::Maybe(a), Signature::Maybe(b)) => a.cmp(b),
+            #[cfg(feature = "gvariant")]
+            (
+                Signature::DictEntry {
+                    key: key_a,
+                    value: value_a,
+                },
+                Signature::DictEntry {
+                    key: key_b,
+                    value: value_b,
+                },
+            ) => match key_a.cmp(key_b) {
+                std::cmp::Ordering::Equal => value_a.cmp(value_b),
+                other => other,
+            },
             (_, _) => std::cmp::Ordering::Equal,
         }
     }
@@ -728,9 +2572,25 @@ impl Serialize for Signature {
 
 impl<'de> Deserialize<'de> for Signature {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        <&str>::deserialize(deserializer).and_then(|s| {
-            Signature::from_str(s).map_err(|e| serde::de::Error::custom(e.to_string()))
-        })
+        struct SignatureVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SignatureVisitor {
+            type Value = Signature;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a D-Bus signature, as a string or bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Signature, E> {
+                Signature::from_str(s).map_err(|e| E::custom(e.to_string()))
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Signature, E> {
+                Signature::from_bytes(bytes).map_err(|e| E::custom(e.to_string()))
+            }
+        }
+
+        deserializer.deserialize_str(SignatureVisitor)
     }
 }
 
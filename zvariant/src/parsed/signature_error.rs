@@ -0,0 +1,76 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A signature parsing error, along with the signature that failed to parse.
+///
+/// This is meant for reporting invalid signatures to end users (e.g in a CLI accepting
+/// signatures as input). Its `Display` implementation renders a two-line diagnostic, with a caret
+/// under the offending character:
+///
+/// ```text
+/// (xa(s)
+///       ^ expected a valid signature character
+/// ```
+///
+/// Use [`Signature::parse_diagnostic`] or [`validate_diagnostic`] to get this error instead of
+/// [`crate::Error`].
+///
+/// # Examples
+///
+/// ```
+/// use zvariant::parsed::Signature;
+///
+/// let err = Signature::parse_diagnostic("a{y}").unwrap_err();
+/// assert_eq!(err.offset, 3);
+/// assert_eq!(
+///     err.to_string(),
+///     "a{y}\n   ^ expected a valid signature character",
+/// );
+/// ```
+///
+/// [`Signature::parse_diagnostic`]: super::Signature::parse_diagnostic
+/// [`validate_diagnostic`]: super::signature::validate_diagnostic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureError {
+    /// The signature that failed to parse.
+    pub input: String,
+    /// The byte offset in `input` at which the error was detected.
+    ///
+    /// For errors about a type rather than a single character (e.g an invalid dictionary key),
+    /// this is the offset of the start of that type.
+    pub offset: usize,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl SignatureError {
+    /// Create a new `SignatureError` for the error `error` that occurred at `offset`, while
+    /// parsing `input`.
+    pub fn new(input: &[u8], offset: usize, error: crate::Error) -> Self {
+        let message = match error {
+            crate::Error::SignatureParse { expected, .. } => format!("expected {expected}"),
+            e => e.to_string(),
+        };
+
+        SignatureError {
+            input: String::from_utf8_lossy(input).into_owned(),
+            offset,
+            message,
+        }
+    }
+}
+
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // `offset` is in bytes but the caret needs to be aligned in characters.
+        let column = self
+            .input
+            .get(..self.offset)
+            .map(|s| s.chars().count())
+            .unwrap_or(self.offset);
+
+        writeln!(f, "{}", self.input)?;
+        write!(f, "{:column$}^ {}", "", self.message)
+    }
+}
+
+impl std::error::Error for SignatureError {}
@@ -0,0 +1,162 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
+
+use super::Signature;
+
+/// A [`Signature`] suitable for use as a key in maps and sets.
+///
+/// It's compared, ordered and hashed by the string form of the signature, which is computed once
+/// on creation. Since it implements `Borrow<str>`, lookups in a `BTreeMap`, `HashMap` etc can be
+/// done with a plain `&str`, without parsing the string into a [`Signature`] first.
+///
+/// Since the string form is kept around, this is also useful whenever a signature's string is
+/// needed repeatedly: unlike [`Signature::to_string`], getting it doesn't allocate every time. It
+/// dereferences to `str`, so it can be passed directly to APIs wanting a `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use std::{collections::BTreeMap, str::FromStr};
+/// use zvariant::parsed::{Signature, SignatureKey};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(SignatureKey::from(Signature::from_str("a{sv}").unwrap()), "dict");
+/// map.insert(SignatureKey::from(Signature::Str), "string");
+///
+/// assert_eq!(map.get("a{sv}"), Some(&"dict"));
+/// assert_eq!(map.get("s"), Some(&"string"));
+/// assert_eq!(map.get("as"), None);
+///
+/// let key = SignatureKey::from_str("a{sv}").unwrap();
+/// assert_eq!(&*key, "a{sv}");
+/// assert_eq!(key.len(), 5);
+/// assert!(key.signature().is_container());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SignatureKey {
+    signature: Signature,
+    string: String,
+}
+
+impl SignatureKey {
+    /// The signature.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// The string form of the signature.
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Convert into the signature.
+    pub fn into_signature(self) -> Signature {
+        self.signature
+    }
+
+    /// Convert into the string form of the signature.
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl From<Signature> for SignatureKey {
+    fn from(signature: Signature) -> Self {
+        let string = signature.to_string();
+
+        SignatureKey { signature, string }
+    }
+}
+
+impl From<SignatureKey> for Signature {
+    fn from(key: SignatureKey) -> Self {
+        key.signature
+    }
+}
+
+impl FromStr for SignatureKey {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Signature::from_str(s).map(Self::from)
+    }
+}
+
+impl TryFrom<&str> for SignatureKey {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl Deref for SignatureKey {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl AsRef<str> for SignatureKey {
+    fn as_ref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl Borrow<str> for SignatureKey {
+    fn borrow(&self) -> &str {
+        &self.string
+    }
+}
+
+impl PartialEq for SignatureKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string
+    }
+}
+
+impl Eq for SignatureKey {}
+
+impl PartialEq<str> for SignatureKey {
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl PartialEq<&str> for SignatureKey {
+    fn eq(&self, other: &&str) -> bool {
+        self.string == *other
+    }
+}
+
+impl PartialOrd for SignatureKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignatureKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.string.cmp(&other.string)
+    }
+}
+
+impl Hash for SignatureKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Must be consistent with `str`'s `Hash` impl, for `Borrow<str>`.
+        self.string.hash(state)
+    }
+}
+
+impl Display for SignatureKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
@@ -8,6 +8,7 @@ macro_rules! validate {
                 let parsed = Signature::from_str($signature).unwrap();
                 assert_eq!(parsed, $expected);
                 assert_eq!(parsed, $signature);
+                assert_eq!(parsed.to_bytes(), parsed.to_string().as_bytes());
             )+
         };
     }
@@ -137,3 +138,1231 @@ fn invalid_strings() {
         "a{yz}"
     );
 }
+
+#[test]
+fn fields() {
+    let sig = Signature::from_str("(ysa{sd})").unwrap();
+    assert!(sig.fields().eq([
+        &Signature::U8,
+        &Signature::Str,
+        &Signature::static_dict(&Signature::Str, &Signature::F64),
+    ]));
+
+    let sig = Signature::static_structure(&[&Signature::I64, &Signature::Str]);
+    assert!(sig.fields().eq([&Signature::I64, &Signature::Str]));
+
+    assert_eq!(Signature::Unit.fields().count(), 0);
+    assert_eq!(Signature::U8.fields().count(), 0);
+    assert_eq!(Signature::from_str("a(yy)").unwrap().fields().count(), 0);
+}
+
+#[test]
+fn containers() {
+    for (signature, is_container, n_fields) in [
+        ("", false, 0),
+        ("y", false, 0),
+        ("v", false, 0),
+        ("ay", true, 0),
+        ("a{sv}", true, 0),
+        ("(y)", true, 1),
+        ("(xa{bs}as)", true, 3),
+        ("xs", true, 2),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert_eq!(sig.is_container(), is_container, "{signature}");
+        assert_eq!(sig.n_fields(), n_fields, "{signature}");
+    }
+    assert_eq!(
+        Signature::static_structure(&[&Signature::U8, &Signature::Str]).n_fields(),
+        2
+    );
+}
+
+#[test]
+fn invalid_string_positions() {
+    for (signature, position, expected) in [
+        ("z", 0, "a valid signature character"),
+        ("xs)", 2, "end of signature"),
+        ("(x", 2, "a valid signature character"),
+        ("(xa(s)", 6, "a valid signature character"),
+        ("a{y}", 3, "a valid signature character"),
+        ("a(sssbbbuuz)", 10, "a valid signature character"),
+    ] {
+        assert_eq!(
+            validate(signature.as_bytes()).unwrap_err(),
+            crate::Error::SignatureParse { position, expected },
+            "{signature}",
+        );
+        assert_eq!(
+            Signature::from_str(signature).unwrap_err(),
+            crate::Error::SignatureParse { position, expected },
+            "{signature}",
+        );
+    }
+}
+
+#[test]
+fn depth() {
+    for (signature, depth) in [
+        ("", 0),
+        ("y", 0),
+        ("ay", 1),
+        ("a{sv}", 1),
+        ("a{sa{sv}}", 2),
+        ("(x(isy))", 2),
+        ("((yyyyuu)a(yv))", 3),
+    ] {
+        assert_eq!(
+            Signature::from_str(signature).unwrap().depth(),
+            depth,
+            "{signature}"
+        );
+    }
+
+    let max_arrays = format!("{}y", "a".repeat(32));
+    assert_eq!(Signature::from_str(&max_arrays).unwrap().depth(), 32);
+    assert!(validate(max_arrays.as_bytes()).is_ok());
+
+    let max_structs = format!("{}y{}", "(".repeat(32), ")".repeat(32));
+    assert_eq!(Signature::from_str(&max_structs).unwrap().depth(), 32);
+    assert!(validate(max_structs.as_bytes()).is_ok());
+}
+
+#[test]
+fn too_deep() {
+    use crate::{Error, MaxDepthExceeded};
+
+    let arrays = format!("{}y", "a".repeat(33));
+    let structs = format!("{}y{}", "(".repeat(33), ")".repeat(33));
+    let dicts = format!("{}y{}", "a{y".repeat(33), "}".repeat(33));
+    // Deep enough to overflow the stack if there was no limit.
+    let very_deep = format!("{}y", "a".repeat(1_000_000));
+
+    for (signature, max) in [
+        (arrays, MaxDepthExceeded::Array),
+        (structs, MaxDepthExceeded::Structure),
+        (dicts, MaxDepthExceeded::Array),
+        (very_deep, MaxDepthExceeded::Array),
+    ] {
+        assert_eq!(
+            validate(signature.as_bytes()).unwrap_err(),
+            Error::SignatureTooDeep(max)
+        );
+        assert_eq!(
+            Signature::from_str(&signature).unwrap_err(),
+            Error::SignatureTooDeep(max)
+        );
+    }
+}
+
+#[test]
+fn alignment() {
+    use crate::serialized::Format;
+
+    for (signature, alignment) in [
+        ("y", 1),
+        ("n", 2),
+        ("s", 4),
+        ("a{sv}", 4),
+        ("x", 8),
+        ("(yy)", 8),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert_eq!(sig.alignment_for(Format::DBus), alignment, "{signature}");
+    }
+
+    #[cfg(feature = "gvariant")]
+    {
+        let sig = Signature::from_str("m(ys)").unwrap();
+        assert_eq!(sig.alignment_for(Format::DBus), 4);
+        assert_eq!(sig.alignment_for(Format::GVariant), 1);
+    }
+}
+
+#[test]
+fn fixed_sized() {
+    for (signature, fixed_sized) in [
+        ("", true),
+        ("y", true),
+        ("d", true),
+        ("s", false),
+        ("v", false),
+        ("ay", false),
+        ("a{yy}", false),
+        ("(yyy)", true),
+        ("(ys)", false),
+        ("((yu)x)", true),
+        ("((yu)a(x))", false),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert_eq!(sig.is_fixed_sized(), fixed_sized, "{signature}");
+    }
+}
+
+#[test]
+fn parse_first() {
+    for (bytes, signature, len) in [
+        (&b"y"[..], "y", 1),
+        (b"yy", "y", 1),
+        (b"a{sv}(ii)", "a{sv}", 5),
+        (b"(xa{bs}as)\0\x01\x02", "(xa{bs}as)", 10),
+        (b"aaysomething", "aay", 3),
+    ] {
+        let (parsed, parsed_len) = Signature::parse_first(bytes).unwrap();
+        assert_eq!(parsed, signature);
+        assert_eq!(parsed_len, len);
+    }
+
+    for bytes in [&b""[..], b")", b"a", b"(yy", b"a{y}"] {
+        assert!(Signature::parse_first(bytes).is_err());
+    }
+}
+
+#[test]
+fn structure_eq_str_mismatched_len() {
+    let sig = Signature::from_str("(yxs)").unwrap();
+    assert_eq!(sig, "(yxs)");
+    assert_eq!(sig, "yxs");
+
+    // Truncated strings.
+    assert_ne!(sig, "yx");
+    assert_ne!(sig, "(yx");
+    assert_ne!(sig, "y");
+    // Right length but not the right contents.
+    assert_ne!(sig, "yxss");
+    assert_ne!(sig, "yyxs)");
+    // Non-ASCII characters.
+    assert_ne!(sig, "é");
+    assert_ne!(sig, "yé");
+    assert_ne!(sig, "(é)");
+}
+
+#[test]
+fn dict_eq_str_non_ascii() {
+    let sig = Signature::from_str("a{yx}").unwrap();
+    assert_eq!(sig, "a{yx}");
+    assert_ne!(sig, "a{éx}");
+    assert_ne!(sig, "a{yé}");
+    assert_ne!(sig, "a{é}");
+
+    #[cfg(feature = "gvariant")]
+    {
+        let sig = Signature::from_str("{yx}").unwrap();
+        assert_eq!(sig, "{yx}");
+        assert_ne!(sig, "{éx}");
+        assert_ne!(sig, "{yé}");
+        assert_ne!(sig, "{é}");
+    }
+}
+
+#[test]
+fn element_and_entry() {
+    let sig = Signature::from_str("aay").unwrap();
+    assert_eq!(
+        sig.element(),
+        Some(&Signature::static_array(&Signature::U8))
+    );
+    assert_eq!(
+        sig.element().and_then(Signature::element),
+        Some(&Signature::U8)
+    );
+    assert!(sig.entry().is_none());
+
+    let sig = Signature::static_dict(&Signature::Str, &Signature::Variant);
+    assert_eq!(sig.entry(), Some((&Signature::Str, &Signature::Variant)));
+    assert!(sig.element().is_none());
+
+    let sig = Signature::from_str("a{sa(ux)}").unwrap();
+    let (key, value) = sig.entry().unwrap();
+    assert_eq!(key, &Signature::Str);
+    assert_eq!(value.element().unwrap(), "(ux)");
+
+    for signature in ["", "y", "(yy)"] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert!(sig.element().is_none());
+        assert!(sig.entry().is_none());
+    }
+}
+
+#[test]
+fn write_bytes() {
+    let mut bytes = b"prefix:".to_vec();
+    Signature::from_str("a{sa(ux)}")
+        .unwrap()
+        .write_bytes(&mut bytes);
+    Signature::from_str("xs").unwrap().write_bytes(&mut bytes);
+    assert_eq!(bytes, b"prefix:a{sa(ux)}(xs)");
+}
+
+#[test]
+fn string_len_const() {
+    const SIGNATURE: Signature = Signature::Structure(FieldsSignatures::Static {
+        fields: &[
+            &Signature::I64,
+            &Signature::Array(ChildSignature::Static {
+                child: &Signature::Structure(FieldsSignatures::Static {
+                    fields: &[&Signature::I32, &Signature::Str, &Signature::U8],
+                }),
+            }),
+            &Signature::Dict {
+                key: ChildSignature::Static {
+                    child: &Signature::Str,
+                },
+                value: ChildSignature::Static {
+                    child: &Signature::Variant,
+                },
+            },
+        ],
+    });
+    const LEN: usize = SIGNATURE.string_len_const();
+    assert_eq!(LEN, "(xa(isy)a{sv})".len());
+    assert_eq!(LEN, SIGNATURE.string_len());
+    assert_eq!(Signature::Unit.string_len_const(), 0);
+}
+
+#[test]
+#[should_panic]
+fn string_len_const_dynamic() {
+    Signature::from_str("a(yy)").unwrap().string_len_const();
+}
+
+#[test]
+fn try_from_bytes() {
+    for signature in ["", "y", "xs", "a{sv}", "(xa(isy))"] {
+        let from_str = Signature::from_str(signature).unwrap();
+        let from_slice = Signature::try_from(signature.as_bytes()).unwrap();
+        let from_vec = Signature::try_from(signature.as_bytes().to_vec()).unwrap();
+        assert_eq!(from_slice, from_str);
+        assert_eq!(from_vec, from_str);
+    }
+    assert_eq!(Signature::try_from(&b""[..]).unwrap(), Signature::Unit);
+
+    for signature in ["a", "(x", "xs)"] {
+        let err = Signature::from_str(signature).unwrap_err();
+        assert_eq!(Signature::try_from(signature.as_bytes()).unwrap_err(), err);
+        assert_eq!(
+            Signature::try_from(signature.as_bytes().to_vec()).unwrap_err(),
+            err
+        );
+    }
+}
+
+#[test]
+fn type_count() {
+    for (signature, count) in [
+        ("", 0),
+        ("y", 1),
+        ("xs", 2),
+        ("a{sv}", 1),
+        ("a{sv}as(ii)", 3),
+        ("(ysa{sd})", 1),
+        ("yyyyuua(yv)", 7),
+    ] {
+        assert_eq!(count_types(signature.as_bytes()).unwrap(), count);
+    }
+
+    for signature in ["a", "(x", "xs)", "a{y}"] {
+        assert_eq!(
+            count_types(signature.as_bytes()).unwrap_err(),
+            validate(signature.as_bytes()).unwrap_err(),
+        );
+    }
+}
+
+#[test]
+fn from_signature() {
+    let sig = crate::Signature::try_from("a{sv}").unwrap();
+    assert_eq!(Signature::from_signature(&sig).unwrap(), "a{sv}");
+    assert_eq!(Signature::from(sig), "a{sv}");
+
+    let sig = crate::Signature::from_str_unchecked("a{sv");
+    assert!(Signature::from_signature(&sig).is_err());
+    assert!(crate::StructureSeed::try_from(sig).is_err());
+}
+
+#[test]
+fn maybe() {
+    for signature in ["my", "a{sms}", "(ymay)"] {
+        #[cfg(feature = "gvariant")]
+        {
+            assert!(validate(signature.as_bytes()).is_ok());
+            assert_eq!(Signature::from_str(signature).unwrap(), signature);
+        }
+        #[cfg(not(feature = "gvariant"))]
+        {
+            assert_eq!(
+                validate(signature.as_bytes()).unwrap_err(),
+                crate::Error::GVariantFeatureDisabled,
+            );
+            assert_eq!(
+                Signature::from_str(signature).unwrap_err(),
+                crate::Error::GVariantFeatureDisabled,
+            );
+        }
+    }
+}
+
+#[test]
+fn dict_entry() {
+    for signature in ["{sv}", "a{sv}", "(y{ss})", "a{s{yv}}"] {
+        #[cfg(feature = "gvariant")]
+        {
+            assert!(validate(signature.as_bytes()).is_ok());
+            assert_eq!(Signature::from_str(signature).unwrap(), signature);
+        }
+        #[cfg(not(feature = "gvariant"))]
+        if signature != "a{sv}" {
+            assert!(validate(signature.as_bytes()).is_err());
+            assert!(Signature::from_str(signature).is_err());
+        }
+    }
+
+    #[cfg(feature = "gvariant")]
+    {
+        use crate::serialized::Format;
+
+        let sig = Signature::from_str("{sv}").unwrap();
+        assert_eq!(
+            sig,
+            Signature::dict_entry(Signature::Str, Signature::Variant)
+        );
+        assert_eq!(sig.to_string(), "{sv}");
+        assert_eq!(sig.string_len(), 4);
+        assert_eq!(sig.alignment_for(Format::GVariant), 8);
+        assert!(!sig.is_fixed_sized());
+        assert_ne!(sig, Signature::dict(Signature::Str, Signature::Variant));
+
+        // Arrays of dict-entries are still dictionaries.
+        let sig = Signature::from_str("a{yu}").unwrap();
+        assert_eq!(sig, Signature::dict(Signature::U8, Signature::U32));
+        assert!(matches!(sig, Signature::Dict { .. }));
+
+        let sig = Signature::from_str("{yu}").unwrap();
+        assert!(sig.is_fixed_sized());
+        assert_eq!(sig.alignment_for(Format::GVariant), 4);
+
+        assert!(Signature::from_str("{s}").is_err());
+        assert!(Signature::from_str("{sss}").is_err());
+    }
+}
+
+#[test]
+fn concat() {
+    let sig = Signature::U8.concat(Signature::Str);
+    assert_eq!(
+        sig,
+        Signature::static_structure(&[&Signature::U8, &Signature::Str])
+    );
+    assert_eq!(sig, "(ys)");
+
+    // Structures are flattened.
+    let sig = Signature::from_str("(ss)").unwrap().concat(Signature::I32);
+    assert_eq!(sig, "(ssi)");
+    let sig = Signature::I32.concat(Signature::from_str("(ss)").unwrap());
+    assert_eq!(sig, "(iss)");
+    let sig = Signature::static_structure(&[&Signature::U8, &Signature::U8])
+        .concat(Signature::from_str("(xa(ii))").unwrap());
+    assert_eq!(sig, "(yyxa(ii))");
+
+    // But not when nested in other containers.
+    let sig = Signature::from_str("a(ss)")
+        .unwrap()
+        .concat(Signature::from_str("a{sv}").unwrap());
+    assert_eq!(sig, "(a(ss)a{sv})");
+
+    // Unit has no fields.
+    assert_eq!(Signature::Unit.concat(Signature::Str), "(s)");
+    assert_eq!(Signature::Str.concat(Signature::Unit), "(s)");
+    let sig = Signature::Unit.concat(Signature::Unit);
+    assert_eq!(sig, Signature::Unit);
+    assert_eq!(sig.to_string(), "");
+}
+
+#[test]
+fn type_char() {
+    for c in "ybnqiuxtdsgov".chars() {
+        let sig = Signature::from_type_char(c).unwrap();
+        assert_eq!(sig.type_char(), Some(c));
+        assert_eq!(sig, Signature::from_str(&c.to_string()).unwrap());
+    }
+    #[cfg(unix)]
+    assert_eq!(Signature::from_type_char('h'), Some(Signature::Fd));
+
+    for c in ['a', '(', ')', '{', '}', 'm', 'z', ' '] {
+        assert!(Signature::from_type_char(c).is_none());
+    }
+    for signature in ["", "ay", "a{sv}", "(yy)"] {
+        assert!(Signature::from_str(signature)
+            .unwrap()
+            .type_char()
+            .is_none());
+    }
+}
+
+#[test]
+fn iterative_parser() {
+    // `from_str` uses the iterative parser while `parse_first` uses the nom-based one, so they
+    // should agree on all single complete types.
+    for signature in [
+        "y",
+        "ay",
+        "aay",
+        "a{sv}",
+        "a{sa{sv}}",
+        "a{sa(ux)}",
+        "(y)",
+        "(x(isy))",
+        "(xa(isy))",
+        "((yyyyuu)a(yv))",
+        "a(a{ya(ss)}(yy))",
+    ] {
+        let iterative = Signature::from_str(signature).unwrap();
+        let (nom, _) = Signature::parse_first(signature.as_bytes()).unwrap();
+        assert_eq!(iterative, nom);
+        assert_eq!(iterative.to_string(), signature);
+    }
+
+    // Errors are the same regardless of the parser.
+    for signature in ["a", "a{y}", "a{yyy}", "()", "(x", "xs)", "}", "a{sv}}"] {
+        assert_eq!(
+            Signature::from_str(signature).unwrap_err(),
+            validate(signature.as_bytes()).unwrap_err(),
+        );
+    }
+}
+
+#[test]
+fn deserialize() {
+    use serde::{
+        de::{
+            value::{BytesDeserializer, Error, StrDeserializer, StringDeserializer},
+            IntoDeserializer,
+        },
+        Deserialize,
+    };
+
+    let sig = Signature::from_str("a{sv}").unwrap();
+
+    let de: StrDeserializer<'_, Error> = "a{sv}".into_deserializer();
+    assert_eq!(Signature::deserialize(de).unwrap(), sig);
+    let de: StringDeserializer<Error> = String::from("a{sv}").into_deserializer();
+    assert_eq!(Signature::deserialize(de).unwrap(), sig);
+    let de = BytesDeserializer::<Error>::new(b"a{sv}");
+    assert_eq!(Signature::deserialize(de).unwrap(), sig);
+    let de = BytesDeserializer::<Error>::new(b"a{sv");
+    assert!(Signature::deserialize(de).is_err());
+
+    let json = serde_json::to_string(&sig).unwrap();
+    assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), sig);
+}
+
+#[test]
+fn eq_signature() {
+    let parsed = Signature::from_str("a{sv}").unwrap();
+    let sig = crate::Signature::try_from("a{sv}").unwrap();
+    let owned = crate::OwnedSignature::from(sig.clone());
+    assert_eq!(parsed, sig);
+    assert_eq!(sig, parsed);
+    assert_eq!(parsed, owned);
+    assert_eq!(owned, parsed);
+
+    let other = crate::Signature::try_from("a{sa{sv}}").unwrap();
+    assert_ne!(other, parsed);
+    assert_ne!(crate::OwnedSignature::from(other), parsed);
+}
+
+#[test]
+fn validated() {
+    for (signature, canonical) in [
+        ("", ""),
+        ("y", "y"),
+        ("a{sv}", "a{sv}"),
+        ("(xs)", "(xs)"),
+        ("xs", "(xs)"),
+        ("ya{sv}(ii)", "(ya{sv}(ii))"),
+    ] {
+        let (parsed, string) = parse_validated(signature.as_bytes()).unwrap();
+        assert_eq!(parsed, Signature::from_str(signature).unwrap());
+        assert_eq!(string, canonical);
+    }
+
+    for signature in ["a", "a{y}", "(x"] {
+        assert_eq!(
+            parse_validated(signature.as_bytes()).unwrap_err(),
+            validate(signature.as_bytes()).unwrap_err(),
+        );
+    }
+}
+
+#[test]
+fn dynamic_string_len() {
+    let sig = Signature::from_str("(xa{sa{sv}}(as))").unwrap();
+    let Signature::Structure(FieldsSignatures::Dynamic { fields }) = &sig else {
+        panic!("expected dynamic fields");
+    };
+    assert_eq!(fields.string_len(), 14);
+    let Signature::Dict { value, .. } = &fields[1] else {
+        panic!("expected a dict");
+    };
+    assert_eq!(value.string_len(), 5);
+
+    // The length is shared by clones.
+    let clone = sig.clone();
+    for _ in 0..2 {
+        assert_eq!(sig.string_len(), 16);
+        assert_eq!(clone.string_len(), 16);
+        assert_eq!(sig.to_string().len(), 16);
+    }
+
+    // The `Rc` is kept as is, rather than copying the signature.
+    let rc = std::rc::Rc::new(Signature::from_str("a{sv}").unwrap());
+    let child = ChildSignature::from(rc.clone());
+    let ChildSignature::Dynamic { child: dynamic } = &child else {
+        panic!("expected a dynamic child");
+    };
+    assert!(std::rc::Rc::ptr_eq(dynamic.as_rc(), &rc));
+    assert_eq!(child.string_len(), 5);
+
+    let rc: std::rc::Rc<[Signature]> = vec![Signature::Str, Signature::Variant].into();
+    let FieldsSignatures::Dynamic { fields } = FieldsSignatures::from(rc.clone()) else {
+        panic!("expected dynamic fields");
+    };
+    assert!(std::rc::Rc::ptr_eq(fields.as_rc(), &rc));
+    assert_eq!(fields.string_len(), 2);
+}
+
+#[test]
+fn into_owned() {
+    fn assert_no_static(sig: &Signature) {
+        match sig {
+            Signature::Array(child) => {
+                assert!(matches!(child, ChildSignature::Dynamic { .. }));
+                assert_no_static(child);
+            }
+            Signature::Dict { key, value } => {
+                assert!(matches!(key, ChildSignature::Dynamic { .. }));
+                assert!(matches!(value, ChildSignature::Dynamic { .. }));
+                assert_no_static(key);
+                assert_no_static(value);
+            }
+            Signature::Structure(fields) => {
+                assert!(matches!(fields, FieldsSignatures::Dynamic { .. }));
+                fields.iter().for_each(assert_no_static);
+            }
+            _ => (),
+        }
+    }
+
+    // A dynamic array of a static structure.
+    let sig = Signature::array(Signature::Structure(FieldsSignatures::Static {
+        fields: &[
+            &Signature::U8,
+            &Signature::Dict {
+                key: ChildSignature::Static {
+                    child: &Signature::Str,
+                },
+                value: ChildSignature::Static {
+                    child: &Signature::Variant,
+                },
+            },
+        ],
+    }));
+    assert!(matches!(
+        sig,
+        Signature::Array(ChildSignature::Dynamic { .. })
+    ));
+
+    let owned = sig.to_owned();
+    assert_no_static(&owned);
+    assert_eq!(owned, sig);
+    assert_eq!(owned, "a(ya{sv})");
+
+    let owned = sig.clone().into_owned();
+    assert_no_static(&owned);
+    assert_eq!(owned, sig);
+}
+
+#[test]
+fn matches_value() {
+    use crate::{Array, StructureBuilder, Value};
+
+    let sig = Signature::from_str("(ua{sv}as)").unwrap();
+    let value = Value::from(
+        StructureBuilder::new()
+            .add_field(42u32)
+            .add_field(std::collections::HashMap::<&str, Value<'_>>::new())
+            .add_field(vec!["zbus"])
+            .build(),
+    );
+    assert!(sig.matches(&value));
+    assert!(!Signature::from_str("(ua{sv}ao)").unwrap().matches(&value));
+    assert!(!Signature::U32.matches(&value));
+
+    // Empty arrays still carry their element signature.
+    let empty = Value::from(Array::new(crate::Signature::try_from("s").unwrap()));
+    assert!(Signature::from_str("as").unwrap().matches(&empty));
+    assert!(!Signature::from_str("ay").unwrap().matches(&empty));
+
+    // A variant value matches its own (inner) signature, not `v`.
+    assert!(Signature::Str.matches(&Value::from("zbus")));
+    assert!(Signature::Variant.matches(&Value::Value(Box::new(Value::from("zbus")))));
+}
+
+#[test]
+fn from_fields() {
+    for signature in ["", "y", "a{sv}", "(xs)", "xs", "ya{sv}(ii)"] {
+        let parsed = Signature::from_str(signature).unwrap();
+        let fields: Vec<_> = match &parsed {
+            // Only the top-level list of types is a flattened structure.
+            Signature::Structure(_) if !signature.starts_with('(') => {
+                parsed.fields().cloned().collect()
+            }
+            Signature::Unit => vec![],
+            _ => vec![parsed.clone()],
+        };
+        assert_eq!(Signature::from_fields(fields), parsed);
+    }
+}
+
+#[test]
+fn unit_fields() {
+    assert!(Signature::Unit.is_empty());
+    assert!(!Signature::structure([Signature::U8, Signature::Str]).is_empty());
+
+    // `structure` keeps `Unit` fields, as tuples containing `()` need them for (de)serialization.
+    let sig = Signature::structure([Signature::U8, Signature::Unit, Signature::Str]);
+    assert_eq!(sig.n_fields(), 3);
+    assert_eq!(sig, "(ys)");
+    assert_eq!(sig.string_len(), 4);
+    assert_eq!(Signature::structure(Vec::new()).n_fields(), 0);
+
+    // `from_fields` drops them.
+    assert_eq!(
+        Signature::from_fields([Signature::U8, Signature::Unit, Signature::Str]).n_fields(),
+        2
+    );
+    assert_eq!(
+        Signature::from_fields([Signature::Unit, Signature::Unit]),
+        Signature::Unit
+    );
+    assert_eq!(Signature::from_fields([]), Signature::Unit);
+
+    assert_eq!(
+        Signature::from_fields([Signature::Unit, Signature::U8, Signature::Unit]),
+        "y"
+    );
+    assert_eq!(Signature::from_fields([Signature::Unit]), "");
+}
+
+#[test]
+fn min_encoded_size() {
+    use crate::{
+        serialized::{Context, Format},
+        to_bytes, Value, LE,
+    };
+    use std::collections::HashMap;
+
+    macro_rules! assert_min_size {
+        ($signature:literal, $value:expr, $format:expr, $ctxt:expr) => {
+            let sig = Signature::from_str($signature).unwrap();
+            let encoded = to_bytes($ctxt, &$value).unwrap();
+            assert_eq!(
+                sig.min_encoded_size($format),
+                encoded.len(),
+                "{}",
+                $signature
+            );
+        };
+    }
+
+    let ctxt = Context::new_dbus(LE, 0);
+    assert_min_size!("", (), Format::DBus, ctxt);
+    assert_min_size!("y", 0u8, Format::DBus, ctxt);
+    assert_min_size!("b", false, Format::DBus, ctxt);
+    assert_min_size!("s", "", Format::DBus, ctxt);
+    assert_min_size!(
+        "g",
+        crate::Signature::from_static_str_unchecked(""),
+        Format::DBus,
+        ctxt
+    );
+    assert_min_size!("v", Value::from(0u8), Format::DBus, ctxt);
+    assert_min_size!("ay", Vec::<u8>::new(), Format::DBus, ctxt);
+    assert_min_size!("ax", Vec::<i64>::new(), Format::DBus, ctxt);
+    assert_min_size!(
+        "a{sv}",
+        HashMap::<&str, Value<'_>>::new(),
+        Format::DBus,
+        ctxt
+    );
+    assert_min_size!(
+        "(ysa{sv}ax)",
+        (
+            0u8,
+            "",
+            HashMap::<&str, Value<'_>>::new(),
+            Vec::<i64>::new()
+        ),
+        Format::DBus,
+        ctxt
+    );
+
+    #[cfg(feature = "gvariant")]
+    {
+        let ctxt = Context::new_gvariant(LE, 0);
+        assert_min_size!("y", 0u8, Format::GVariant, ctxt);
+        assert_min_size!("s", "", Format::GVariant, ctxt);
+        assert_min_size!("v", Value::from(0u8), Format::GVariant, ctxt);
+        assert_min_size!("ax", Vec::<i64>::new(), Format::GVariant, ctxt);
+        assert_min_size!("mi", None::<i32>, Format::GVariant, ctxt);
+        assert_min_size!("(yx)", (0u8, 0i64), Format::GVariant, ctxt);
+        assert_min_size!(
+            "(sasy)",
+            ("", Vec::<&str>::new(), 0u8),
+            Format::GVariant,
+            ctxt
+        );
+    }
+}
+
+#[test]
+fn into_iter() {
+    for (signature, expected) in [
+        ("", &[][..]),
+        ("s", &["s"][..]),
+        ("a{sv}", &["a{sv}"][..]),
+        ("(xa{bs}as)", &["x", "a{bs}", "as"][..]),
+        ("ya(ii)", &["y", "a(ii)"][..]),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        let iter = sig.into_iter();
+        assert_eq!(iter.len(), expected.len());
+        let types: Vec<_> = iter.map(|s| s.to_string()).collect();
+        assert_eq!(types, expected);
+    }
+
+    let sig = Signature::static_structure(&[&Signature::U8, &Signature::Variant]);
+    let types: Vec<_> = (&sig).into_iter().cloned().collect();
+    assert_eq!(types, [Signature::U8, Signature::Variant]);
+}
+
+#[test]
+fn empty_struct() {
+    use crate::Error;
+
+    for signature in ["()", "(())", "a()", "(x())", "a{s()}", "y()"] {
+        assert_eq!(
+            Signature::from_str(signature).unwrap_err(),
+            Error::EmptyStruct,
+            "{signature}"
+        );
+        assert_eq!(
+            validate(signature.as_bytes()).unwrap_err(),
+            Error::EmptyStruct
+        );
+    }
+}
+
+#[test]
+fn from_char_unchecked() {
+    for c in "ybnqiuxtdsgov".bytes() {
+        assert_eq!(
+            Signature::from_char_unchecked(c),
+            Signature::from_type_char(c as char).unwrap()
+        );
+        assert_eq!(
+            Signature::from_char_unchecked(c),
+            Signature::from_bytes(&[c]).unwrap()
+        );
+    }
+    #[cfg(unix)]
+    assert_eq!(Signature::from_char_unchecked(b'h'), Signature::Fd);
+
+    for c in "a({".bytes() {
+        assert!(std::panic::catch_unwind(|| Signature::from_char_unchecked(c)).is_err());
+    }
+}
+
+#[cfg(feature = "gvariant")]
+#[test]
+fn gvariant_fixed_size() {
+    for (signature, size) in [
+        ("y", Some(1)),
+        ("b", Some(1)),
+        ("q", Some(2)),
+        ("u", Some(4)),
+        ("d", Some(8)),
+        ("(yu)", Some(8)),
+        ("(uy)", Some(8)),
+        ("(yyq)", Some(4)),
+        ("(y(tq))", Some(24)),
+        ("{yu}", Some(8)),
+        ("s", None),
+        ("v", None),
+        ("ay", None),
+        ("my", None),
+        ("(ys)", None),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert_eq!(sig.gvariant_fixed_size(), size, "{signature}");
+    }
+}
+
+#[test]
+fn validate_with_limits() {
+    use super::signature::validate_with_limits;
+    use crate::{Error, MaxDepthExceeded};
+
+    assert!(validate_with_limits(b"", 0, 0).is_ok());
+    assert!(validate_with_limits(b"ss", 2, 0).is_ok());
+    assert_eq!(
+        validate_with_limits(b"sss", 2, 0).unwrap_err(),
+        Error::SignatureTooLong(2)
+    );
+    // The length is checked first, even for invalid signatures.
+    assert_eq!(
+        validate_with_limits(b"a{", 1, 0).unwrap_err(),
+        Error::SignatureTooLong(1)
+    );
+
+    let too_deep = Error::SignatureTooDeep(MaxDepthExceeded::Container);
+    assert!(validate_with_limits(b"(s)a{sv}", 16, 1).is_ok());
+    assert_eq!(validate_with_limits(b"(s)", 16, 0).unwrap_err(), too_deep);
+    assert_eq!(validate_with_limits(b"sa(s)", 16, 0).unwrap_err(), too_deep);
+    assert!(validate_with_limits(b"(xa{bs}as)", 16, 2).is_ok());
+    assert_eq!(
+        validate_with_limits(b"(xa{bs}as)", 16, 1).unwrap_err(),
+        too_deep
+    );
+
+    // A list of types isn't a structure.
+    assert!(validate_with_limits(b"sss", 16, 0).is_ok());
+    assert_eq!(validate_with_limits(b"(sss)", 16, 0).unwrap_err(), too_deep);
+    assert!(validate_with_limits(b"a{s(s)}a(x)", 16, 2).is_ok());
+
+    // The specification's checks still apply.
+    let arrays = format!("{}y", "a".repeat(33));
+    assert_eq!(
+        validate_with_limits(arrays.as_bytes(), 64, 64).unwrap_err(),
+        Error::SignatureTooDeep(MaxDepthExceeded::Array)
+    );
+    assert!(validate_with_limits(b"a{vs}", 16, 16).is_err());
+    assert!(validate_with_limits(b"a{sv", 16, 16).is_err());
+}
+
+#[test]
+fn display_alternate() {
+    let sig = Signature::from_str("a{s(ua{sv}as)}").unwrap();
+    assert_eq!(sig.to_string(), "a{s(ua{sv}as)}");
+    assert_eq!(
+        format!("{sig:#}"),
+        "\
+dict:
+  key: s
+  value: struct:
+    u
+    dict:
+      key: s
+      value: v
+    array of:
+      s"
+    );
+
+    assert_eq!(format!("{:#}", Signature::Str), "s");
+    assert_eq!(format!("{:#}", Signature::Unit), "unit");
+    assert_eq!(
+        format!("{:#}", Signature::from_str("yaay").unwrap()),
+        "struct:\n  y\n  array of:\n    array of:\n      y"
+    );
+}
+
+#[test]
+fn is_basic() {
+    for signature in ["y", "b", "n", "q", "i", "u", "x", "t", "d", "s", "g", "o"] {
+        assert!(
+            Signature::from_str(signature).unwrap().is_basic(),
+            "{signature}"
+        );
+    }
+    #[cfg(unix)]
+    assert!(Signature::Fd.is_basic());
+
+    for signature in ["", "v", "as", "a{sv}", "(ii)"] {
+        assert!(
+            !Signature::from_str(signature).unwrap().is_basic(),
+            "{signature}"
+        );
+    }
+}
+
+#[test]
+fn invalid_dict_key() {
+    use crate::Error;
+
+    for signature in [
+        "a{(ii)s}",
+        "a{vs}",
+        "a{ass}",
+        "a{a{ss}s}",
+        "(ya{(y)s})",
+        "a{sa{vs}}",
+    ] {
+        assert_eq!(
+            Signature::from_str(signature).unwrap_err(),
+            Error::InvalidDictKey,
+            "{signature}"
+        );
+        assert_eq!(
+            validate(signature.as_bytes()).unwrap_err(),
+            Error::InvalidDictKey
+        );
+    }
+
+    for signature in ["a{yv}", "a{bs}", "a{d(ii)}", "a{gas}", "a{oa{sv}}"] {
+        assert!(Signature::from_str(signature).is_ok(), "{signature}");
+        assert!(validate(signature.as_bytes()).is_ok());
+    }
+}
+
+#[test]
+fn signature_key() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let keys = ["a{sv}", "s", "(ii)", "as", ""];
+    let btree: BTreeMap<_, _> = keys
+        .iter()
+        .map(|k| (SignatureKey::from_str(k).unwrap(), *k))
+        .collect();
+    let hash: HashMap<_, _> = keys
+        .iter()
+        .map(|k| (SignatureKey::from_str(k).unwrap(), *k))
+        .collect();
+    for k in keys {
+        assert_eq!(btree.get(k), Some(&k));
+        assert_eq!(hash.get(k), Some(&k));
+    }
+    assert_eq!(btree.len(), keys.len());
+    assert!(!btree.contains_key("i"));
+    assert!(!hash.contains_key("i"));
+
+    let key = SignatureKey::from(Signature::dict(Signature::Str, Signature::Variant));
+    assert_eq!(key, "a{sv}");
+    assert_eq!(key.as_str(), "a{sv}");
+    assert_eq!(
+        key.signature(),
+        &Signature::dict(Signature::Str, Signature::Variant)
+    );
+    assert!(SignatureKey::from_str("a{").is_err());
+}
+
+#[test]
+fn top_level_flatten() {
+    for (signature, expected) in [
+        ("i", "i"),
+        ("(i)", "i"),
+        ("(((s)))", "s"),
+        ("((ii))", "(ii)"),
+        ("(a(i))", "a(i)"),
+        ("a((i))", "a((i))"),
+        ("", ""),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert_eq!(sig.top_level_flatten(), expected, "{signature}");
+    }
+
+    let sig = Signature::static_structure(&[&Signature::U8]);
+    assert_eq!(sig.top_level_flatten(), Signature::U8);
+}
+
+#[test]
+fn signature_key_string() {
+    let sig = SignatureKey::from_str("(sa{sv})").unwrap();
+    assert_eq!(&*sig, "(sa{sv})");
+    assert_eq!(sig.as_str(), "(sa{sv})");
+    assert_eq!(sig.to_string(), "(sa{sv})");
+    assert_eq!(sig.signature().n_fields(), 2);
+    assert!(sig.starts_with('('));
+    assert_eq!(sig, SignatureKey::try_from("(sa{sv})").unwrap());
+
+    let sig = SignatureKey::from(Signature::dict(Signature::Str, Signature::Variant));
+    assert_eq!(AsRef::<str>::as_ref(&sig), "a{sv}");
+    assert_eq!(sig.clone().into_string(), "a{sv}");
+    assert_eq!(
+        sig.into_signature(),
+        Signature::dict(Signature::Str, Signature::Variant)
+    );
+
+    assert!(SignatureKey::from_str("a{sv").is_err());
+}
+
+#[test]
+fn child_at() {
+    use ContainerStep::*;
+
+    let sig = Signature::from_str("a{s(ua{sv})}").unwrap();
+    assert_eq!(sig.child_at(&[]), Some(&sig));
+    assert_eq!(sig.child_at(&[DictKey]), Some(&Signature::Str));
+    assert_eq!(sig.child_at(&[DictValue]).unwrap(), "(ua{sv})");
+    assert_eq!(sig.child_at(&[DictValue, Field(0)]), Some(&Signature::U32));
+    assert_eq!(
+        sig.child_at(&[DictValue, Field(1), DictValue]),
+        Some(&Signature::Variant)
+    );
+
+    // Steps that don't match the container.
+    assert_eq!(sig.child_at(&[ArrayElement]), None);
+    assert_eq!(sig.child_at(&[DictValue, Field(2)]), None);
+    assert_eq!(sig.child_at(&[DictKey, DictKey]), None);
+    assert_eq!(sig.child_at(&[DictValue, Field(0), Field(0)]), None);
+
+    let sig = Signature::from_str("aas").unwrap();
+    assert_eq!(
+        sig.child_at(&[ArrayElement, ArrayElement]),
+        Some(&Signature::Str)
+    );
+    assert_eq!(sig.child_at(&[ArrayElement, Field(0)]), None);
+
+    #[cfg(feature = "gvariant")]
+    {
+        let sig = Signature::from_str("ma(ms)").unwrap();
+        assert_eq!(
+            sig.child_at(&[MaybeInner, ArrayElement, Field(0), MaybeInner]),
+            Some(&Signature::Str)
+        );
+        assert_eq!(sig.child_at(&[ArrayElement]), None);
+    }
+}
+
+#[test]
+fn common_prefix() {
+    for (a, b, prefix) in [
+        // Divergence in the first, middle and last field.
+        ("(sxa{sv})", "(ixa{sv})", ""),
+        ("(sxa{sv})", "(sta{sv})", "(s)"),
+        ("(sxa{sv})", "(sxa{sy})", "(sx)"),
+        // No divergence.
+        ("(sxa{sv})", "(sxa{sv})", "(sxa{sv})"),
+        ("(sx)", "(sxa{sv})", "(sx)"),
+        // Non-structures.
+        ("a{sv}", "a{sv}", "a{sv}"),
+        ("a{sv}", "a{sy}", ""),
+        ("s", "(sx)", ""),
+        ("", "", ""),
+    ] {
+        let a = Signature::from_str(a).unwrap();
+        let b = Signature::from_str(b).unwrap();
+        assert_eq!(a.common_prefix(&b), prefix);
+        assert_eq!(b.common_prefix(&a), prefix);
+    }
+}
+
+#[test]
+fn complete_types() {
+    for signature in [
+        "",
+        "y",
+        "sia{sv}",
+        "(xs)",
+        "ya{sv}(ii)aas",
+        "a(a{ya(ss)}(yy))v",
+    ] {
+        let types = split_complete_types(signature.as_bytes()).unwrap();
+        assert_eq!(types.len(), count_types(signature.as_bytes()).unwrap());
+        assert_eq!(types.concat(), signature);
+        for t in &types {
+            assert_eq!(count_types(t.as_bytes()).unwrap(), 1);
+        }
+    }
+
+    // Errors are the same as when validating.
+    for signature in ["a", "a{y}", "sa{sv", "s)", "(x", "z", "()"] {
+        assert_eq!(
+            split_complete_types(signature.as_bytes()).unwrap_err(),
+            validate(signature.as_bytes()).unwrap_err(),
+            "{signature}"
+        );
+    }
+}
+
+#[test]
+fn signature_error() {
+    let err = Signature::parse_diagnostic("(xa(s)").unwrap_err();
+    assert_eq!(err.input, "(xa(s)");
+    assert_eq!(err.offset, 6);
+    assert_eq!(err.message, "expected a valid signature character");
+    assert_eq!(
+        err.to_string(),
+        "(xa(s)\n      ^ expected a valid signature character"
+    );
+    assert_eq!(validate_diagnostic(b"(xa(s)").unwrap_err(), err);
+
+    // Errors about a whole type point at the start of that type.
+    let err = validate_diagnostic(b"a{(i)s}").unwrap_err();
+    assert_eq!(err.offset, 2);
+    assert_eq!(
+        err.to_string(),
+        "a{(i)s}\n  ^ Invalid signature: dictionary keys must be basic types"
+    );
+    assert_eq!(Signature::parse_diagnostic("a{(i)s}").unwrap_err(), err);
+
+    let err = Signature::parse_diagnostic("sa()").unwrap_err();
+    assert_eq!(err.offset, 2);
+    assert_eq!(
+        err.to_string(),
+        "sa()\n  ^ Invalid signature: empty structures are not allowed"
+    );
+
+    let arrays = format!("{}y", "a".repeat(33));
+    let err = validate_diagnostic(arrays.as_bytes()).unwrap_err();
+    assert_eq!(err.offset, 32);
+    assert_eq!(
+        err.to_string(),
+        format!("{arrays}\n{}^ {}", " ".repeat(32), err.message)
+    );
+    assert_eq!(
+        err.message,
+        "Invalid signature: Maximum allowed depth for arrays in encoding was exceeded"
+    );
+
+    #[cfg(not(feature = "gvariant"))]
+    {
+        let err = validate_diagnostic(b"(smy)").unwrap_err();
+        assert_eq!(err.offset, 2);
+        assert_eq!(
+            err.to_string(),
+            "(smy)\n  ^ Signature contains GVariant-specific types but the `gvariant` feature is \
+             disabled"
+        );
+    }
+
+    assert!(Signature::parse_diagnostic("a{sv}").is_ok());
+    assert!(validate_diagnostic(b"a{sv}").is_ok());
+}
+
+#[test]
+fn rust_type_hint() {
+    for (signature, hint) in [
+        ("", "()"),
+        ("y", "u8"),
+        ("b", "bool"),
+        ("x", "i64"),
+        ("d", "f64"),
+        ("s", "String"),
+        ("g", "OwnedSignature"),
+        ("o", "OwnedObjectPath"),
+        ("v", "OwnedValue"),
+        ("as", "Vec<String>"),
+        ("a{sv}", "HashMap<String, OwnedValue>"),
+        ("(s)", "(String,)"),
+        ("(xa{bs}as)", "(i64, HashMap<bool, String>, Vec<String>)"),
+        (
+            "aa{oa(uv)}",
+            "Vec<HashMap<OwnedObjectPath, Vec<(u32, OwnedValue)>>>",
+        ),
+    ] {
+        let sig = Signature::from_str(signature).unwrap();
+        assert_eq!(sig.rust_type_hint(), hint, "{signature}");
+    }
+    #[cfg(unix)]
+    assert_eq!(Signature::Fd.rust_type_hint(), "OwnedFd");
+}
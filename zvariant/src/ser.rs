@@ -167,7 +167,7 @@ where
     S::Error: Into<Error>,
     T: ?Sized + Serialize,
 {
-    let signature = parsed::Signature::from(signature.try_into().map_err(Into::into)?);
+    let signature = parsed::Signature::from_signature(&signature.try_into().map_err(Into::into)?)?;
 
     to_writer_for_parsed_signature(writer, ctxt, &signature, value)
 }
@@ -255,7 +255,7 @@ where
     S::Error: Into<Error>,
     T: ?Sized + Serialize,
 {
-    let signature = parsed::Signature::from(signature.try_into().map_err(Into::into)?);
+    let signature = parsed::Signature::from_signature(&signature.try_into().map_err(Into::into)?)?;
 
     to_bytes_for_parsed_signature(ctxt, &signature, value)
 }
@@ -281,7 +281,8 @@ impl<'bytes, 'fds> Data<'bytes, 'fds> {
         S: TryInto<Signature<'d>>,
         S::Error: Into<Error>,
     {
-        let signature = signature.try_into().map_err(Into::into)?.into();
+        let signature = signature.try_into().map_err(Into::into)?;
+        let signature = parsed::Signature::from_signature(&signature)?;
 
         self.deserialize_for_parsed_signature(&signature)
     }
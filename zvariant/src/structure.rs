@@ -116,7 +116,7 @@ impl TryFrom<Signature<'_>> for StructureSeed<'static> {
     type Error = zvariant::Error;
 
     fn try_from(signature: Signature<'_>) -> Result<Self, zvariant::Error> {
-        let signature = signature.into();
+        let signature = parsed::Signature::from_signature(&signature)?;
         if !matches!(signature, parsed::Signature::Structure(_)) {
             return Err(zvariant::Error::IncorrectType);
         }
@@ -158,7 +158,8 @@ pub trait DynamicDeserialize<'de>: DynamicType {
         S: TryInto<Signature<'de>>,
         S::Error: Into<zvariant::Error>,
     {
-        let parsed_sig = signature.try_into().map_err(Into::into)?.into();
+        let signature = signature.try_into().map_err(Into::into)?;
+        let parsed_sig = parsed::Signature::from_signature(&signature)?;
 
         Self::deserializer_for_parsed_signature(&parsed_sig)
     }